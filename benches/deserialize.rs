@@ -0,0 +1,127 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use ruddr::model::{allocation, time, types};
+use std::hint::black_box;
+
+// list response sizes: one full page of results and then ten pages worth of results
+const SIZES: [usize; 2] = [100, 1000];
+
+const TIME_ENTRY: &str = r#"{
+  "id": "4497fa99-27a4-4509-9748-83e4399296e3",
+  "typeId": "project_time",
+  "statusId": "approved",
+  "clientStatusId": "not_created",
+  "date": "2022-03-08",
+  "minutes": 120,
+  "timerStartedAt": "2022-03-10T17:50:48.808Z",
+  "notes": null,
+  "isBillable": true,
+  "invoiced": true,
+  "rateCurrency": "USD",
+  "rate": 100,
+  "costCurrency": "USD",
+  "costPerHour": 50,
+  "createdAt": "2022-03-11T16:13:40.715Z",
+  "startTime": "01:00",
+  "endTime": "03:00",
+  "member": {"id": "ec5543de-3b0f-47a0-b8ef-a6e18dc4b885", "name": "John Smith"},
+  "project": {
+    "id": "095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+    "name": "Vendor Portal",
+    "client": {"id": "4cacdf11-71d1-4fbb-90ee-b091803581b0", "name": "Joe's Shop"}
+  },
+  "role": {"id": "7ad5a34a-07b7-48e9-a760-bd220d52e354", "name": "Project Manager"},
+  "task": {"id": "9a7097a0-d71e-4ed2-9bc3-2dd7d797edc4", "name": "Project Status Reporting"},
+  "timeOffType": null,
+  "timesheet": {"id": "8c3f7a92-e14b-4d60-b5a1-2f09d7c63e18", "submittedOn": "2025-01-19T17:32:10.541Z"},
+  "invoice": null
+}"#;
+
+const ALLOCATION: &str = r#"{
+  "id": "212b8272-ed2a-4a91-950a-8a06b3546144",
+  "resourceTypeId": "member",
+  "assignmentTypeId": "project",
+  "start": "2022-06-01",
+  "end": "2022-08-31",
+  "unit": "day",
+  "hoursPerDay": 8,
+  "hoursPerWeek": null,
+  "hoursPerMonth": null,
+  "hoursPerAllocation": null,
+  "hoursRatioOfCapacity": null,
+  "allocateOnTimeOffDays": false,
+  "totalHours": 528,
+  "isBillable": true,
+  "bookingTypeId": "confirmed",
+  "notes": null,
+  "readOnly": false,
+  "entity": "allocation",
+  "createdAt": "2022-03-02T17:40:03.633Z",
+  "member": {"id": "ec5543de-3b0f-47a0-b8ef-a6e18dc4b885", "name": "John Smith"},
+  "placeholder": null,
+  "project": {
+    "id": "095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+    "name": "Vendor Portal",
+    "client": {"id": "4cacdf11-71d1-4fbb-90ee-b091803581b0", "name": "Joe's Shop"}
+  },
+  "role": {"id": "7ad5a34a-07b7-48e9-a760-bd220d52e354", "name": "Project Manager"},
+  "task": null,
+  "timeOffType": null
+}"#;
+
+// construct a list response body with the item repeated size times
+fn list_response(item: &str, size: usize) -> String {
+    format!(
+        r#"{{"results": [{}], "hasMore": true}}"#,
+        vec![item; size].join(",")
+    )
+}
+
+fn bench_time_entries(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("time_entries");
+    for size in SIZES {
+        let body = list_response(TIME_ENTRY, size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &body, |bencher, body| {
+            bencher.iter(|| serde_json::from_str::<time::TimeEntries>(black_box(body)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_allocations(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("allocations");
+    for size in SIZES {
+        let body = list_response(ALLOCATION, size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &body, |bencher, body| {
+            bencher
+                .iter(|| serde_json::from_str::<allocation::Allocations>(black_box(body)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_types(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("types");
+    group.bench_function("date", |bencher| {
+        bencher.iter(|| types::Date::try_from(black_box("2022-03-08")).unwrap())
+    });
+    group.bench_function("timestamp", |bencher| {
+        bencher.iter(|| types::Timestamp::try_from(black_box("2022-03-11T16:13:40.715Z")).unwrap())
+    });
+    group.bench_function("time", |bencher| {
+        bencher.iter(|| types::Time::try_from(black_box("01:00")).unwrap())
+    });
+    group.bench_function("uuid", |bencher| {
+        bencher.iter(|| {
+            types::UUID::try_from(black_box("4497fa99-27a4-4509-9748-83e4399296e3")).unwrap()
+        })
+    });
+    group.bench_function("slug", |bencher| {
+        bencher.iter(|| types::Slug::try_from(black_box("vendor-portal")).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_time_entries, bench_allocations, bench_types);
+criterion_main!(benches);
@@ -0,0 +1,391 @@
+//! # Client
+//!
+//! `blocking::client` consists of functions for initializing blocking Ruddr API clients, and executing requests with those clients on the current thread. Request construction, response deserialization, and errors are shared with the asynchronous `client::client`.
+use log;
+use reqwest;
+use serde::{Serialize, de};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+use crate::client::{cache, client, error, metrics, request, resource, trace};
+use crate::model::types;
+
+/// Blocking client struct for reuse with various and multiple requests without an asynchronous runtime.
+#[derive(Debug)]
+pub struct Client {
+    client: reqwest::blocking::Client,
+    cache: Option<cache::Cache>,
+    metrics: Box<dyn metrics::MetricsRecorder>,
+}
+
+impl Client {
+    /// Instantiate a reusable blocking Ruddr client through a constructor that returns a wrapped `Client` struct and boxed error.
+    /// ```ignore
+    /// // token as environment variable
+    /// unsafe { std::env::set_var("RUDDR_TOKEN", "abcdefghi123456789"); }
+    /// let client = Client::new(None)?;
+    /// // token as parameter value
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// ```
+    pub fn new(token: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
+        // construct headers with json encoding and authentication
+        let headers = client::headers(token)?;
+
+        // build client
+        let client = reqwest::blocking::Client::builder()
+            .default_headers(headers)
+            .build()?;
+
+        log::debug!("built client is {client:?}");
+        Ok(Self {
+            client,
+            cache: None,
+            metrics: Box::new(metrics::NoopRecorder),
+        })
+    }
+
+    /// Enable the in-memory response cache for Read and List operations. Create and Update operations invalidate the cached responses of their resource.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?.with_cache(
+    ///     Cache::new(Duration::from_secs(60)).with_ttl::<member::Member>(Duration::from_secs(3600)),
+    /// );
+    /// ```
+    pub fn with_cache(mut self, cache: cache::Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Invoke the metrics recorder after each request to the Ruddr API with the endpoint template, status, and duration.
+    /// ```ignore
+    /// let recorder = Arc::new(InMemoryRecorder::default());
+    /// let client = Client::new(Some("abcdefghi123456789"))?.with_metrics(recorder.clone());
+    /// ```
+    pub fn with_metrics(mut self, recorder: impl metrics::MetricsRecorder + 'static) -> Self {
+        self.metrics = Box::new(recorder);
+        self
+    }
+
+    /// Returns the response cache if enabled, so that cached responses can be invalidated explicitly.
+    /// ```ignore
+    /// if let Some(cache) = client.cache() {
+    ///     cache.invalidate_resource::<project::Project>();
+    /// }
+    /// ```
+    pub fn cache(&self) -> Option<&cache::Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Retrieves (GET) a specific Ruddr object of any resource type by id, and deserializes it to the corresponding model struct.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let project = client.get::<project::Project>(
+    ///     types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"),
+    /// )?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.get",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, id = %id)
+        )
+    )]
+    pub fn get<Resource: resource::Resource>(
+        &self,
+        id: types::Id<Resource>,
+    ) -> Result<Resource, error::Error> {
+        self.read::<Resource>(&format!("{}/{id}", Resource::ENDPOINT), None)
+    }
+
+    /// Retrieves (GET) Ruddr objects of any resource type by ids with at most `concurrency` requests in flight on scoped threads. Duplicate ids are retrieved once, and failures are returned per id instead of failing the batch.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let members = client.get_many::<member::Member>(
+    ///     time_entries.results.iter().map(|entry| entry.member.id.clone()),
+    ///     8,
+    /// );
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.get_many",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, concurrency)
+        )
+    )]
+    pub fn get_many<Resource: resource::Resource + Send>(
+        &self,
+        ids: impl IntoIterator<Item = types::Id<Resource>>,
+        concurrency: usize,
+    ) -> resource::Batch<Resource> {
+        let ids = resource::dedupe(ids);
+        log::debug!(
+            "retrieving {} objects from {}",
+            ids.len(),
+            Resource::ENDPOINT
+        );
+
+        // retrieve objects on worker threads sharing the ids and results
+        let workers = concurrency.max(1).min(ids.len());
+        let ids = Mutex::new(ids.into_iter());
+        let batch = Mutex::new(resource::Batch::default());
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    loop {
+                        // release the ids lock before the request
+                        let Some(id) = ids.lock().unwrap().next() else {
+                            break;
+                        };
+                        let result =
+                            self.read::<Resource>(&format!("{}/{id}", Resource::ENDPOINT), None);
+                        batch.lock().unwrap().insert(id, result);
+                    }
+                });
+            }
+        });
+        batch.into_inner().unwrap()
+    }
+
+    /// Retrieves (GET) the first 100 Ruddr objects of any resource type by filters, and deserializes them to the corresponding list model struct.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let projects = client.list::<project::Project>(&ProjectsQuery {
+    ///     name_contains: Some("Portal"),
+    ///     ..Default::default()
+    /// })?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.list",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, page_size = tracing::field::Empty, has_more = tracing::field::Empty)
+        )
+    )]
+    pub fn list<Resource: resource::Resource>(
+        &self,
+        query: &Resource::Query<'_>,
+    ) -> Result<Resource::List, error::Error> {
+        use resource::{Page, Query};
+        let list = self.read::<Resource::List>(Resource::ENDPOINT, Some(&query.params()))?;
+        trace::record_page(list.results().len(), list.has_more());
+        Ok(list)
+    }
+
+    /// Retrieves (GET) a specific Ruddr generic object by id, or specific generic objects by filters, and deserializes it/them to the corresponding struct/structs.
+    /// This is the escape hatch for endpoints, parameters, and fields not yet modeled by this crate, and the endpoint is relative to the Ruddr API workspace path. An empty endpoint or unparseable URL returns `Error::InvalidRequest`.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let deser_response_read = client.read::<project::Project>(
+    ///     "projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+    ///     None,
+    /// )?;
+    /// let deser_response_list = client.read::<project::Projects>(
+    ///     "projects",
+    ///     Some("limit=100"),
+    /// )?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.read",
+            skip_all,
+            fields(
+                endpoint = %endpoint,
+                params = trace::redact(params).unwrap_or_default(),
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                cached = false,
+            )
+        )
+    )]
+    pub fn read<Response: de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: Option<&str>,
+    ) -> Result<Response, error::Error> {
+        // construct and assign client request
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, params)?;
+        log::debug!("request is {request:?}");
+
+        // use cached response if fresh, and otherwise revalidate it with its etag if stale
+        let stale = match self
+            .cache
+            .as_ref()
+            .map(|cache| cache.lookup(endpoint, params))
+        {
+            Some(cache::Lookup::Fresh(body)) => {
+                log::debug!("using cached response for {endpoint}");
+                trace::record_cached();
+                return client::deserialize(endpoint, reqwest::StatusCode::OK, &body);
+            }
+            Some(cache::Lookup::Stale { etag, body }) => Some((etag, body)),
+            _ => None,
+        };
+        let (etag, stale) = stale.unzip();
+
+        // retrieve object and deser
+        let response = request.blocking_get(&self.client, etag.as_deref());
+        metrics::observe(
+            self.metrics.as_ref(),
+            "GET",
+            endpoint,
+            response
+                .as_ref()
+                .ok()
+                .map(reqwest::blocking::Response::status),
+            start,
+        );
+        let response = response?;
+        let status = response.status();
+        trace::record_response(status, start);
+        let etag = client::etag_header(response.headers());
+        let body = response.text()?;
+        let deser = client::read_through::<Response>(
+            self.cache(),
+            endpoint,
+            params,
+            status,
+            etag,
+            body,
+            stale,
+        )?;
+
+        log::debug!("successful read from Ruddr API");
+        Ok(deser)
+    }
+
+    /// Retrieves (GET) a specific Ruddr generic object by id, or specific generic objects by filters, as raw JSON. This is useful for endpoints and fields not yet modeled by this crate.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let json = client.read_json("projects", Some("limit=100&nameContains=Portal"))?;
+    /// let has_more = json["hasMore"].as_bool();
+    /// ```
+    pub fn read_json(
+        &self,
+        endpoint: &str,
+        params: Option<&str>,
+    ) -> Result<serde_json::Value, error::Error> {
+        self.read::<serde_json::Value>(endpoint, params)
+    }
+
+    /// Creates (POST) a Ruddr generic object from an input model, and deserializes the created object to the corresponding struct.
+    /// This is public only to interface at the moment, but is abstract enough that assistance is super helpful to future me, and so documentation exists here.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let deser_response = client.create::<project::ProjectInput, project::Project>(
+    ///     "projects",
+    ///     &project_input,
+    /// )?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.create",
+            skip_all,
+            fields(endpoint = %endpoint, status = tracing::field::Empty, latency_ms = tracing::field::Empty)
+        )
+    )]
+    pub(crate) fn create<Body: Serialize, Response: de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Body,
+    ) -> Result<Response, error::Error> {
+        // construct and assign client request
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, None)?;
+        log::debug!("request is {request:?}");
+
+        // create object and deser
+        let response = request.blocking_post(&self.client, body);
+        metrics::observe(
+            self.metrics.as_ref(),
+            "POST",
+            endpoint,
+            response
+                .as_ref()
+                .ok()
+                .map(reqwest::blocking::Response::status),
+            start,
+        );
+        let response = response?;
+        trace::record_response(response.status(), start);
+        let deser = Self::deserialize::<Response>(endpoint, response)?;
+
+        // invalidate cached responses of the modified resource
+        if let Some(cache) = &self.cache {
+            cache.invalidate_endpoint(endpoint);
+        }
+
+        log::debug!("successful create with Ruddr API");
+        Ok(deser)
+    }
+
+    /// Updates (PATCH) a specific Ruddr generic object by id from an input model, and deserializes the updated object to the corresponding struct.
+    /// This is public only to interface at the moment, but is abstract enough that assistance is super helpful to future me, and so documentation exists here.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let deser_response = client.update::<project::ProjectInput, project::Project>(
+    ///     "projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+    ///     &project_input,
+    /// )?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.update",
+            skip_all,
+            fields(endpoint = %endpoint, status = tracing::field::Empty, latency_ms = tracing::field::Empty)
+        )
+    )]
+    pub(crate) fn update<Body: Serialize, Response: de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Body,
+    ) -> Result<Response, error::Error> {
+        // construct and assign client request
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, None)?;
+        log::debug!("request is {request:?}");
+
+        // update object and deser
+        let response = request.blocking_patch(&self.client, body);
+        metrics::observe(
+            self.metrics.as_ref(),
+            "PATCH",
+            endpoint,
+            response
+                .as_ref()
+                .ok()
+                .map(reqwest::blocking::Response::status),
+            start,
+        );
+        let response = response?;
+        trace::record_response(response.status(), start);
+        let deser = Self::deserialize::<Response>(endpoint, response)?;
+
+        // invalidate cached responses of the modified resource
+        if let Some(cache) = &self.cache {
+            cache.invalidate_endpoint(endpoint);
+        }
+
+        log::debug!("successful update with Ruddr API");
+        Ok(deser)
+    }
+
+    // deserialize response body to the corresponding struct if successful, and provide information if failure
+    fn deserialize<Response: de::DeserializeOwned>(
+        endpoint: &str,
+        response: reqwest::blocking::Response,
+    ) -> Result<Response, error::Error> {
+        let status = response.status();
+        let body = response.text()?;
+        client::deserialize(endpoint, status, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests;
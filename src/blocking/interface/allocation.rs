@@ -0,0 +1,59 @@
+//! # Allocation
+//!
+//! `blocking::interface::allocation` consists of blocking functions for interfacing with the Ruddr Allocation endpoints. Query structs are shared with and re-exported from `interface::allocation`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::allocation::AllocationsQuery;
+use crate::model::{allocation, types};
+
+/// Retrieves a specific Ruddr Allocation object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/allocations/get-an-allocation.md)
+/// ```ignore
+/// let allocation = allocation(&client, types::Id::try_from("212b8272-ed2a-4a91-950a-8a06b3546144").expect("uuid conversion failed"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn allocation(
+    client: &client::Client,
+    id: types::Id<allocation::Allocation>,
+) -> Result<allocation::Allocation, error::Error> {
+    // retrieve allocation
+    client.get::<allocation::Allocation>(id)
+}
+
+/// Retrieves the first 100 Ruddr Allocation objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/allocations/list-allocations.md)
+/// ```ignore
+/// let allocations = allocations(
+///     &client,
+///     &AllocationsQuery {
+///         assignment_type: Some(allocation::AssignmentType::Project),
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///         ..AllocationsQuery::overlapping(
+///             types::Date::try_from("2024-01-01").expect("date conversion failed"),
+///             types::Date::try_from("2024-01-31").expect("date conversion failed"),
+///         )
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn allocations(
+    client: &client::Client,
+    query: &AllocationsQuery,
+) -> Result<allocation::Allocations, error::Error> {
+    // retrieve allocations
+    client.list::<allocation::Allocation>(query)
+}
+
+/// Retrieves specific Ruddr Allocation objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = allocations_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn allocations_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<allocation::Allocation>>,
+    concurrency: usize,
+) -> resource::Batch<allocation::Allocation> {
+    // retrieve allocations
+    client.get_many::<allocation::Allocation>(ids, concurrency)
+}
@@ -0,0 +1,52 @@
+//! # Cost
+//!
+//! `blocking::interface::cost` consists of blocking functions for interfacing with the Ruddr Cost period endpoints. Query structs are shared with and re-exported from `interface::cost`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::cost::CostsQuery;
+use crate::model::{cost, types};
+
+/// Retrieves a specific Ruddr Cost period object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/cost-periods/get-a-cost-period.md)
+/// ```ignore
+/// let cost = cost(&client, types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn cost(
+    client: &client::Client,
+    id: types::Id<cost::Cost>,
+) -> Result<cost::Cost, error::Error> {
+    // retrieve cost target period
+    client.get::<cost::Cost>(id)
+}
+
+/// Retrieves the first 100 Ruddr Cost period objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/cost-periods/list-cost-periods.md)
+/// ```ignore
+/// let costs = costs(
+///     &client,
+///     &CostsQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn costs(client: &client::Client, query: &CostsQuery) -> Result<cost::Costs, error::Error> {
+    // retrieve cost target periods
+    client.list::<cost::Cost>(query)
+}
+
+/// Retrieves specific Ruddr Cost period objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = costs_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn costs_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<cost::Cost>>,
+    concurrency: usize,
+) -> resource::Batch<cost::Cost> {
+    // retrieve cost periods
+    client.get_many::<cost::Cost>(ids, concurrency)
+}
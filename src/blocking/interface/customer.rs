@@ -0,0 +1,107 @@
+//! # Customer
+//!
+//! `blocking::interface::customer` consists of blocking functions for interfacing with the Ruddr Client endpoints. This module and base Read function are named differently from the endpoint so as to avoid naming collisions with the API client module in external usage. All other code associated with this endpoint utilizes the endpoint name `client`. Query structs are shared with and re-exported from `interface::customer`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::customer::ClientsQuery;
+use crate::model;
+
+/// Retrieves a specific Ruddr Client object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/get-a-client.md)
+/// ```ignore
+/// let customer = customer(&client, types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn customer(
+    client: &client::Client,
+    id: model::types::Id<model::client::Client>,
+) -> Result<model::client::Client, error::Error> {
+    // retrieve client
+    client.get::<model::client::Client>(id)
+}
+
+/// Retrieves the first 100 Ruddr Client objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/list-clients.md)
+/// ```ignore
+/// let clients = clients(
+///     &client,
+///     &ClientsQuery {
+///         code: Some("JOE"),
+///         record_status: Some(model::shared::RecordStatus::Active),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn clients(
+    client: &client::Client,
+    query: &ClientsQuery<'_>,
+) -> Result<model::client::Clients, error::Error> {
+    // retrieve clients
+    client.list::<model::client::Client>(query)
+}
+
+/// Creates a Ruddr Client object from the input model after client-side validation, and deserializes the created object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/create-a-client.md)
+/// ```ignore
+/// let customer = create_client(
+///     &client,
+///     &model::client::ClientInput {
+///         name: Some(String::from("Joe's Shop")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn create_client(
+    client: &client::Client,
+    input: &model::client::ClientInput,
+) -> Result<model::client::Client, error::Error> {
+    // validate input
+    input.validate_create()?;
+
+    // create client
+    client.create::<model::client::ClientInput, model::client::Client>("clients", input)
+}
+
+/// Updates a specific Ruddr Client object by id from the input model after client-side validation, and deserializes the updated object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/update-a-client.md)
+/// ```ignore
+/// let customer = update_client(
+///     &client,
+///     types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0").expect("invalid UUID"),
+///     &model::client::ClientInput {
+///         record_status_id: Some(model::shared::RecordStatus::Archived),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn update_client(
+    client: &client::Client,
+    id: model::types::Id<model::client::Client>,
+    input: &model::client::ClientInput,
+) -> Result<model::client::Client, error::Error> {
+    // validate input
+    input.validate()?;
+
+    // update client
+    client.update::<model::client::ClientInput, model::client::Client>(
+        &format!("clients/{id}"),
+        input,
+    )
+}
+
+/// Retrieves specific Ruddr Client objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = clients_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn clients_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = model::types::Id<model::client::Client>>,
+    concurrency: usize,
+) -> resource::Batch<model::client::Client> {
+    // retrieve clients
+    client.get_many::<model::client::Client>(ids, concurrency)
+}
@@ -0,0 +1,56 @@
+//! # Expense Item
+//!
+//! `blocking::interface::expense_item` consists of blocking functions for interfacing with the Ruddr Expense Item endpoints. Query structs are shared with and re-exported from `interface::expense_item`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::expense_item::ExpenseItemsQuery;
+use crate::model::{expense_item, types};
+
+/// Retrieves a specific Ruddr Expense Item object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/expense-items/get-an-expense-item.md)
+/// ```ignore
+/// let expense_item = expense_item(&client, types::Id::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_item(
+    client: &client::Client,
+    id: types::Id<expense_item::ExpenseItem>,
+) -> Result<expense_item::ExpenseItem, error::Error> {
+    // retrieve expense item
+    client.get::<expense_item::ExpenseItem>(id)
+}
+
+/// Retrieves the first 100 Ruddr Expense Item objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/expense-items/list-expense-items.md)
+/// ```ignore
+/// let expense_items = expense_items(
+///     &client,
+///     &ExpenseItemsQuery {
+///         expense_report: Some(types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID")),
+///         invoiced: Some(false),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_items(
+    client: &client::Client,
+    query: &ExpenseItemsQuery,
+) -> Result<expense_item::ExpenseItems, error::Error> {
+    // retrieve expense items
+    client.list::<expense_item::ExpenseItem>(query)
+}
+
+/// Retrieves specific Ruddr Expense item objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = expense_items_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_items_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<expense_item::ExpenseItem>>,
+    concurrency: usize,
+) -> resource::Batch<expense_item::ExpenseItem> {
+    // retrieve expense items
+    client.get_many::<expense_item::ExpenseItem>(ids, concurrency)
+}
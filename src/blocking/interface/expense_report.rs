@@ -0,0 +1,56 @@
+//! # Expense Report
+//!
+//! `blocking::interface::expense_report` consists of blocking functions for interfacing with the Ruddr Expense Report endpoints. Query structs are shared with and re-exported from `interface::expense_report`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::expense_report::ExpenseReportsQuery;
+use crate::model::{expense_report, types};
+
+/// Retrieves a specific Ruddr Expense Report object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/expense-reports/get-an-expense-report.md)
+/// ```ignore
+/// let expense_report = expense_report(&client, types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_report(
+    client: &client::Client,
+    id: types::Id<expense_report::ExpenseReport>,
+) -> Result<expense_report::ExpenseReport, error::Error> {
+    // retrieve expense report
+    client.get::<expense_report::ExpenseReport>(id)
+}
+
+/// Retrieves the first 100 Ruddr Expense Report objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/expense-reports/list-expense-reports.md)
+/// ```ignore
+/// let expense_reports = expense_reports(
+///     &client,
+///     &ExpenseReportsQuery {
+///         date_on_after: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///         date_on_before: Some(types::Date::try_from("2024-01-31").expect("date conversion failed")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_reports(
+    client: &client::Client,
+    query: &ExpenseReportsQuery,
+) -> Result<expense_report::ExpenseReports, error::Error> {
+    // retrieve expense reports
+    client.list::<expense_report::ExpenseReport>(query)
+}
+
+/// Retrieves specific Ruddr Expense report objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = expense_reports_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn expense_reports_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<expense_report::ExpenseReport>>,
+    concurrency: usize,
+) -> resource::Batch<expense_report::ExpenseReport> {
+    // retrieve expense reports
+    client.get_many::<expense_report::ExpenseReport>(ids, concurrency)
+}
@@ -0,0 +1,57 @@
+//! # Member
+//!
+//! `blocking::interface::member` consists of blocking functions for interfacing with the Ruddr Member endpoints. Query structs are shared with and re-exported from `interface::member`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::member::MembersQuery;
+use crate::model::{member, types};
+
+/// Retrieves a specific Ruddr Workspace Member object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/members/get-a-member.md)
+/// ```ignore
+/// let member = member(&client, types::Id::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn member(
+    client: &client::Client,
+    id: types::Id<member::Member>,
+) -> Result<member::Member, error::Error> {
+    // retrieve member
+    client.get::<member::Member>(id)
+}
+
+/// Retrieves the first 100 Ruddr Workspace Member objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/members/list-members.md)
+/// ```ignore
+/// let members = members(
+///     &client,
+///     &MembersQuery {
+///         name_contains: Some("Joe"),
+///         email_contains: Some("foo@bar.com"),
+///         is_active: Some(true),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn members(
+    client: &client::Client,
+    query: &MembersQuery<'_>,
+) -> Result<member::Members, error::Error> {
+    // retrieve members
+    client.list::<member::Member>(query)
+}
+
+/// Retrieves specific Ruddr Member objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = members_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn members_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<member::Member>>,
+    concurrency: usize,
+) -> resource::Batch<member::Member> {
+    // retrieve members
+    client.get_many::<member::Member>(ids, concurrency)
+}
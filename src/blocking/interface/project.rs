@@ -0,0 +1,109 @@
+//! # Project
+//!
+//! `blocking::interface::project` consists of blocking functions for interfacing with the Ruddr Project endpoints. Query structs are shared with and re-exported from `interface::project`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::project::ProjectsQuery;
+use crate::model::{project, types};
+
+/// Retrieves a specific Ruddr Project object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/get-a-project.md)
+/// ```ignore
+/// let project = project(&client, types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn project(
+    client: &client::Client,
+    id: types::Id<project::Project>,
+) -> Result<project::Project, error::Error> {
+    // retrieve project
+    client.get::<project::Project>(id)
+}
+
+/// Retrieves the first 100 Ruddr Project objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/list-projects.md)
+/// ```ignore
+/// let projects = projects(
+///     &client,
+///     &ProjectsQuery {
+///         client_id: Some(types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
+///         status: Some(project::Status::InProgress),
+///         record_status: Some(shared::RecordStatus::Active),
+///         name_contains: Some("my_project"),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn projects(
+    client: &client::Client,
+    query: &ProjectsQuery<'_>,
+) -> Result<project::Projects, error::Error> {
+    // retrieve projects
+    client.list::<project::Project>(query)
+}
+
+/// Creates a Ruddr Project object from the input model after client-side validation, and deserializes the created object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/create-a-project.md)
+/// ```ignore
+/// let project = create_project(
+///     &client,
+///     &project::ProjectInput {
+///         name: Some(String::from("Vendor Portal")),
+///         client_id: Some(types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
+///         billing_type_id: Some(project::BillingType::Fixed),
+///         fixed_fee: Some(shared::Decimal::new(50000, 0)),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn create_project(
+    client: &client::Client,
+    input: &project::ProjectInput,
+) -> Result<project::Project, error::Error> {
+    // validate input
+    input.validate_create()?;
+
+    // create project
+    client.create::<project::ProjectInput, project::Project>("projects", input)
+}
+
+/// Updates a specific Ruddr Project object by id from the input model after client-side validation, and deserializes the updated object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/update-a-project.md)
+/// ```ignore
+/// let project = update_project(
+///     &client,
+///     types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"),
+///     &project::ProjectInput {
+///         status_id: Some(project::Status::Completed),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn update_project(
+    client: &client::Client,
+    id: types::Id<project::Project>,
+    input: &project::ProjectInput,
+) -> Result<project::Project, error::Error> {
+    // validate input
+    input.validate()?;
+
+    // update project
+    client.update::<project::ProjectInput, project::Project>(&format!("projects/{id}"), input)
+}
+
+/// Retrieves specific Ruddr Project objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = projects_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn projects_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<project::Project>>,
+    concurrency: usize,
+) -> resource::Batch<project::Project> {
+    // retrieve projects
+    client.get_many::<project::Project>(ids, concurrency)
+}
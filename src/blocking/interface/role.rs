@@ -0,0 +1,52 @@
+//! # Role
+//!
+//! `blocking::interface::role` consists of blocking functions for interfacing with the Ruddr Role endpoints. Query structs are shared with and re-exported from `interface::role`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::role::RolesQuery;
+use crate::model::{role, types};
+
+/// Retrieves a specific Ruddr Role object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/get-a-project-role.md)
+/// ```ignore
+/// let role = role(&client, types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354").expect("uuid conversion failed"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn role(
+    client: &client::Client,
+    id: types::Id<role::Role>,
+) -> Result<role::Role, error::Error> {
+    // retrieve role
+    client.get::<role::Role>(id)
+}
+
+/// Retrieves the first 100 Ruddr Role objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/project-roles/list-project-roles.md)
+/// ```ignore
+/// let roles = roles(
+///     &client,
+///     &RolesQuery {
+///         project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("uuid conversion failed")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn roles(client: &client::Client, query: &RolesQuery) -> Result<role::Roles, error::Error> {
+    // retrieve roles
+    client.list::<role::Role>(query)
+}
+
+/// Retrieves specific Ruddr Role objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = roles_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn roles_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<role::Role>>,
+    concurrency: usize,
+) -> resource::Batch<role::Role> {
+    // retrieve roles
+    client.get_many::<role::Role>(ids, concurrency)
+}
@@ -0,0 +1,60 @@
+//! # Time
+//!
+//! `blocking::interface::time` consists of blocking functions for interfacing with the Ruddr Time Entry endpoints. Query structs are shared with and re-exported from `interface::time`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::time::TimeEntriesQuery;
+use crate::model::{time, types};
+
+/// Retrieves a specific Ruddr Time Entry object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/time-entries/get-a-time-entry.md)
+/// ```ignore
+/// let time_entry = time_entry(&client, types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn time_entry(
+    client: &client::Client,
+    id: types::Id<time::TimeEntry>,
+) -> Result<time::TimeEntry, error::Error> {
+    // retrieve time entry
+    client.get::<time::TimeEntry>(id)
+}
+
+/// Retrieves the first 100 Ruddr Time Entry objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/time-entries/list-time-entries.md)
+/// ```ignore
+/// let time_entries = time_entries(
+///     &client,
+///     &TimeEntriesQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///         project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")),
+///         time_type: Some(time::Type::ProjectTime),
+///         client_status: Some(time::ClientStatus::Approved),
+///         invoiced: Some(false),
+///         begin_date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn time_entries(
+    client: &client::Client,
+    query: &TimeEntriesQuery,
+) -> Result<time::TimeEntries, error::Error> {
+    // retrieve time entries
+    client.list::<time::TimeEntry>(query)
+}
+
+/// Retrieves specific Ruddr Time entry objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = time_entries_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn time_entries_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<time::TimeEntry>>,
+    concurrency: usize,
+) -> resource::Batch<time::TimeEntry> {
+    // retrieve time entries
+    client.get_many::<time::TimeEntry>(ids, concurrency)
+}
@@ -0,0 +1,55 @@
+//! # Utilization
+//!
+//! `blocking::interface::utilization` consists of blocking functions for interfacing with the Ruddr Utilization target period endpoints. Query structs are shared with and re-exported from `interface::utilization`.
+use crate::blocking::client;
+use crate::client::{error, resource};
+pub use crate::interface::utilization::UtilizationsQuery;
+use crate::model::{types, utilization};
+
+/// Retrieves a specific Ruddr Utilization target period object by id, and deserializes it to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/utilization-target-periods/get-a-utilization-target-period.md)
+/// ```ignore
+/// let utilization = utilization(&client, types::Id::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab").expect("invalid UUID"))?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn utilization(
+    client: &client::Client,
+    id: types::Id<utilization::Utilization>,
+) -> Result<utilization::Utilization, error::Error> {
+    // retrieve utilization target period
+    client.get::<utilization::Utilization>(id)
+}
+
+/// Retrieves the first 100 Ruddr Utilization target period objects by query filters, and deserializes it to the corresponding vector of model structs.
+/// [API Documentation](https://docs.ruddr.io/api-reference/utilization-target-periods/list-utilization-target-periods.md)
+/// ```ignore
+/// let utilizations = utilizations(
+///     &client,
+///     &UtilizationsQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///         ..Default::default()
+///     },
+/// )?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn utilizations(
+    client: &client::Client,
+    query: &UtilizationsQuery,
+) -> Result<utilization::Utilizations, error::Error> {
+    // retrieve utilization target periods
+    client.list::<utilization::Utilization>(query)
+}
+
+/// Retrieves specific Ruddr Utilization target period objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = utilizations_by_ids(&client, ids, 8);
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn utilizations_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<utilization::Utilization>>,
+    concurrency: usize,
+) -> resource::Batch<utilization::Utilization> {
+    // retrieve utilization target periods
+    client.get_many::<utilization::Utilization>(ids, concurrency)
+}
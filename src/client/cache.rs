@@ -0,0 +1,184 @@
+//! # Cache
+//!
+//! `client::cache` consists of an optional in-memory response cache for Read and List operations. Responses are cached by endpoint and query, expire after a time to live configurable per resource, and are revalidated with `If-None-Match` when the Ruddr API returned an `ETag` for them.
+use log;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::resource;
+
+// default maximum number of cached responses
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// In-memory response cache for use with a `Client`. Cached response bodies are shared by typed and raw JSON reads of the same endpoint and query.
+/// The cache holds at most 1024 responses by default, and evicts the response closest to or furthest past expiration when full.
+/// ```ignore
+/// let cache = Cache::new(Duration::from_secs(60))
+///     .with_ttl::<member::Member>(Duration::from_secs(3600))
+///     .with_ttl::<project::Project>(Duration::from_secs(3600));
+/// let client = Client::new(Some("abcdefghi123456789"))?.with_cache(cache);
+/// ```
+#[derive(Debug)]
+pub struct Cache {
+    default_ttl: Duration,
+    ttls: HashMap<&'static str, Duration>,
+    capacity: usize,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+// cached response body with its validator and expiration
+#[derive(Debug)]
+struct Entry {
+    body: String,
+    etag: Option<String>,
+    expires: Instant,
+}
+
+// result of a cache lookup
+#[derive(PartialEq, Debug)]
+pub(crate) enum Lookup {
+    // unexpired body to be used without a request
+    Fresh(String),
+    // expired body with a validator to be revalidated with a conditional request, where the body is retained in case the entry is removed while the request is in flight
+    Stale { etag: String, body: String },
+    // nothing usable in the cache
+    Miss,
+}
+
+impl Cache {
+    /// Instantiate an empty cache where responses expire after the default time to live unless overridden for a resource.
+    pub fn new(default_ttl: Duration) -> Self {
+        Self {
+            default_ttl,
+            ttls: HashMap::new(),
+            capacity: DEFAULT_CAPACITY,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Override the time to live of responses for a resource type. A zero time to live revalidates the response on every read when possible.
+    pub fn with_ttl<Resource: resource::Resource>(mut self, ttl: Duration) -> Self {
+        self.ttls.insert(Resource::ENDPOINT, ttl);
+        self
+    }
+
+    /// Override the maximum number of cached responses, which is at least one.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Remove the cached response for a specific endpoint and query e.g. `projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c` and `None`.
+    pub fn invalidate(&self, endpoint: &str, params: Option<&str>) {
+        self.entries.lock().unwrap().remove(&key(endpoint, params));
+    }
+
+    /// Remove all cached responses for a resource type including both Read and List operations.
+    pub fn invalidate_resource<Resource: resource::Resource>(&self) {
+        self.invalidate_endpoint(Resource::ENDPOINT);
+    }
+
+    /// Remove all cached responses.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    // remove all cached responses for the resource of the endpoint
+    pub(crate) fn invalidate_endpoint(&self, endpoint: &str) {
+        let root = root(endpoint);
+        log::debug!("invalidating cached responses for {root}");
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|key, _| root_of_key(key) != root);
+    }
+
+    // retrieve the cached response for the endpoint and query if usable, and evict it if expired without a validator
+    pub(crate) fn lookup(&self, endpoint: &str, params: Option<&str>) -> Lookup {
+        let key = key(endpoint, params);
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(&key) {
+            Some(entry) if entry.expires > Instant::now() => Lookup::Fresh(entry.body.clone()),
+            Some(Entry {
+                etag: Some(etag),
+                body,
+                ..
+            }) => Lookup::Stale {
+                etag: etag.clone(),
+                body: body.clone(),
+            },
+            Some(_) => {
+                entries.remove(&key);
+                Lookup::Miss
+            }
+            None => Lookup::Miss,
+        }
+    }
+
+    // extend the expiration of a cached response after successful revalidation if it was not removed in the meantime
+    pub(crate) fn revalidate(&self, endpoint: &str, params: Option<&str>) {
+        let expires = Instant::now() + self.ttl(endpoint);
+        if let Some(entry) = self.entries.lock().unwrap().get_mut(&key(endpoint, params)) {
+            entry.expires = expires;
+        }
+    }
+
+    // cache a successful response body with its validator
+    pub(crate) fn store(
+        &self,
+        endpoint: &str,
+        params: Option<&str>,
+        body: String,
+        etag: Option<String>,
+    ) {
+        let now = Instant::now();
+        let entry = Entry {
+            body,
+            etag,
+            expires: now + self.ttl(endpoint),
+        };
+        let mut entries = self.entries.lock().unwrap();
+        // sweep expired responses which cannot be revalidated so that unread entries do not accumulate
+        entries.retain(|_, entry| entry.etag.is_some() || entry.expires > now);
+        // evict the response closest to or furthest past expiration when full so that stale responses with validators are also bounded
+        let key = key(endpoint, params);
+        if entries.len() >= self.capacity
+            && !entries.contains_key(&key)
+            && let Some(evicted) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires)
+                .map(|(key, _)| key.clone())
+        {
+            log::debug!("evicting cached response for {evicted} at capacity");
+            entries.remove(&evicted);
+        }
+        entries.insert(key, entry);
+    }
+
+    // time to live for responses of the resource of the endpoint
+    fn ttl(&self, endpoint: &str) -> Duration {
+        *self.ttls.get(root(endpoint)).unwrap_or(&self.default_ttl)
+    }
+}
+
+// cache key composed of endpoint and query
+fn key(endpoint: &str, params: Option<&str>) -> String {
+    match params {
+        Some(params) => format!("{endpoint}?{params}"),
+        None => String::from(endpoint),
+    }
+}
+
+// resource of the endpoint e.g. `projects` for `projects/{id}`
+fn root(endpoint: &str) -> &str {
+    endpoint.split('/').next().unwrap_or(endpoint)
+}
+
+// resource of the cache key
+fn root_of_key(key: &str) -> &str {
+    root(key.split('?').next().unwrap_or(key))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,152 @@
+use super::*;
+use crate::model::{member, project};
+
+#[test]
+fn test_cache_key() {
+    assert_eq!(key("projects", Some("limit=100")), "projects?limit=100");
+    assert_eq!(key("projects/abc", None), "projects/abc");
+    assert_eq!(root("projects/abc"), "projects");
+    assert_eq!(root_of_key("projects?limit=100"), "projects");
+}
+
+#[test]
+fn test_cache_lookup() {
+    let cache = Cache::new(Duration::from_secs(60)).with_ttl::<member::Member>(Duration::ZERO);
+    assert_eq!(cache.lookup("projects", None), Lookup::Miss);
+
+    // fresh with default ttl
+    cache.store("projects", None, String::from("{}"), None);
+    assert_eq!(
+        cache.lookup("projects", None),
+        Lookup::Fresh(String::from("{}"))
+    );
+    assert_eq!(cache.lookup("projects", Some("limit=100")), Lookup::Miss);
+
+    // expired immediately with overridden ttl, and revalidated only with etag
+    cache.store("members/abc", None, String::from("{}"), None);
+    assert_eq!(cache.lookup("members/abc", None), Lookup::Miss);
+    cache.store(
+        "members",
+        Some("limit=100"),
+        String::from("[]"),
+        Some(String::from("\"v1\"")),
+    );
+    assert_eq!(
+        cache.lookup("members", Some("limit=100")),
+        Lookup::Stale {
+            etag: String::from("\"v1\""),
+            body: String::from("[]"),
+        }
+    );
+}
+
+#[test]
+fn test_cache_revalidate() {
+    let cache = Cache::new(Duration::ZERO).with_ttl::<project::Project>(Duration::from_secs(60));
+    cache.store(
+        "members",
+        None,
+        String::from("[]"),
+        Some(String::from("\"v1\"")),
+    );
+    cache.revalidate("members", None);
+    assert!(matches!(
+        cache.lookup("members", None),
+        Lookup::Stale { .. }
+    ));
+
+    // revalidation extends the expiration by the ttl
+    cache.store(
+        "projects",
+        None,
+        String::from("[]"),
+        Some(String::from("\"v1\"")),
+    );
+    cache.revalidate("projects", None);
+    assert_eq!(
+        cache.lookup("projects", None),
+        Lookup::Fresh(String::from("[]"))
+    );
+
+    // removed entries are not restored
+    cache.invalidate("projects", None);
+    cache.revalidate("projects", None);
+    assert_eq!(cache.lookup("projects", None), Lookup::Miss);
+}
+
+#[test]
+fn test_cache_evict() {
+    let cache = Cache::new(Duration::ZERO);
+    cache.store("projects/abc", None, String::from("{}"), None);
+    cache.store(
+        "members/abc",
+        None,
+        String::from("{}"),
+        Some(String::from("\"v1\"")),
+    );
+
+    // expired entries without a validator are evicted on lookup
+    cache.store("projects/def", None, String::from("{}"), None);
+    assert_eq!(cache.lookup("projects/def", None), Lookup::Miss);
+    assert!(!cache.entries.lock().unwrap().contains_key("projects/def"));
+
+    // and swept on store, while those with a validator are retained
+    cache.store("roles/abc", None, String::from("{}"), None);
+    let entries = cache.entries.lock().unwrap();
+    assert!(!entries.contains_key("projects/abc"));
+    assert!(entries.contains_key("members/abc"));
+    assert!(entries.contains_key("roles/abc"));
+}
+
+#[test]
+fn test_cache_invalidate() {
+    let cache = Cache::new(Duration::from_secs(60));
+    cache.store("projects", Some("limit=100"), String::from("{}"), None);
+    cache.store("projects/abc", None, String::from("{}"), None);
+    cache.store("project-roles/abc", None, String::from("{}"), None);
+    cache.store("members/abc", None, String::from("{}"), None);
+
+    cache.invalidate("members/abc", None);
+    assert_eq!(cache.lookup("members/abc", None), Lookup::Miss);
+
+    cache.invalidate_resource::<project::Project>();
+    assert_eq!(cache.lookup("projects", Some("limit=100")), Lookup::Miss);
+    assert_eq!(cache.lookup("projects/abc", None), Lookup::Miss);
+    assert_eq!(
+        cache.lookup("project-roles/abc", None),
+        Lookup::Fresh(String::from("{}"))
+    );
+
+    cache.clear();
+    assert_eq!(cache.lookup("project-roles/abc", None), Lookup::Miss);
+}
+
+#[test]
+fn test_cache_capacity() {
+    let cache = Cache::new(Duration::from_secs(60))
+        .with_ttl::<member::Member>(Duration::ZERO)
+        .with_capacity(2);
+    cache.store(
+        "members/abc",
+        None,
+        String::from("{}"),
+        Some(String::from("\"v1\"")),
+    );
+    cache.store("projects/abc", None, String::from("{}"), None);
+
+    // expired response with a validator is evicted first when full
+    cache.store("projects/def", None, String::from("{}"), None);
+    assert_eq!(cache.lookup("members/abc", None), Lookup::Miss);
+    assert_eq!(cache.entries.lock().unwrap().len(), 2);
+
+    // replacing a cached response does not evict another
+    cache.store("projects/abc", None, String::from("[]"), None);
+    assert_eq!(
+        cache.lookup("projects/def", None),
+        Lookup::Fresh(String::from("{}"))
+    );
+    assert_eq!(
+        cache.lookup("projects/abc", None),
+        Lookup::Fresh(String::from("[]"))
+    );
+}
@@ -1,17 +1,27 @@
 //! # Client
 //!
 //! `client::client` consists of functions for initializing Ruddr API clients, and initiating requests with those clients.
+use futures_util::stream::{self, StreamExt};
 use log;
 use reqwest;
-use serde::de;
+use serde::{Serialize, de};
 use std::env;
+use std::time::Instant;
 
+use super::cache;
+use super::error;
+use super::metrics;
 use super::request;
+use super::resource;
+use super::trace;
+use crate::model::types;
 
 /// Client struct for reuse with various and multiple requests without explicit reqwest type usage.
 #[derive(Debug)]
 pub struct Client {
     client: reqwest::Client,
+    cache: Option<cache::Cache>,
+    metrics: Box<dyn metrics::MetricsRecorder>,
 }
 
 impl Client {
@@ -24,30 +34,8 @@ impl Client {
     /// let client = Client::new(Some("abcdefghi123456789"))?;
     /// ```
     pub fn new(token: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
-        // initialize headers and establish json encoding
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-        // determine authentication input method
-        let bearer_token = match token {
-            Some(token) => format!("Bearer {token}"),
-            None => match env::var("RUDDR_TOKEN") {
-                Ok(token) => format!("Bearer {token}"),
-                Err(_) => {
-                    return Err(Box::from(
-                        "ruddr api token was not input through code or RUDDR_TOKEN environment variable",
-                    ));
-                }
-            },
-        };
-        // establish authentication and mark as sensitive
-        let mut auth_token = reqwest::header::HeaderValue::from_str(&bearer_token)?;
-        auth_token.set_sensitive(true);
-        headers.insert(reqwest::header::AUTHORIZATION, auth_token);
-
-        log::debug!("built headers are {headers:?}");
+        // construct headers with json encoding and authentication
+        let headers = headers(token)?;
 
         // build client
         let client = reqwest::Client::builder()
@@ -55,11 +43,141 @@ impl Client {
             .build()?;
 
         log::debug!("built client is {client:?}");
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            cache: None,
+            metrics: Box::new(metrics::NoopRecorder),
+        })
+    }
+
+    /// Enable the in-memory response cache for Read and List operations. Create and Update operations invalidate the cached responses of their resource.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?.with_cache(
+    ///     Cache::new(Duration::from_secs(60)).with_ttl::<member::Member>(Duration::from_secs(3600)),
+    /// );
+    /// ```
+    pub fn with_cache(mut self, cache: cache::Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Invoke the metrics recorder after each request to the Ruddr API with the endpoint template, status, and duration.
+    /// ```ignore
+    /// let recorder = Arc::new(InMemoryRecorder::default());
+    /// let client = Client::new(Some("abcdefghi123456789"))?.with_metrics(recorder.clone());
+    /// ```
+    pub fn with_metrics(mut self, recorder: impl metrics::MetricsRecorder + 'static) -> Self {
+        self.metrics = Box::new(recorder);
+        self
+    }
+
+    /// Returns the response cache if enabled, so that cached responses can be invalidated explicitly.
+    /// ```ignore
+    /// if let Some(cache) = client.cache() {
+    ///     cache.invalidate_resource::<project::Project>();
+    /// }
+    /// ```
+    pub fn cache(&self) -> Option<&cache::Cache> {
+        self.cache.as_ref()
+    }
+
+    /// Retrieves (GET) a specific Ruddr object of any resource type by id, and deserializes it to the corresponding model struct.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let project = client.get::<project::Project>(
+    ///     types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"),
+    /// ).await?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.get",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, id = %id)
+        )
+    )]
+    pub async fn get<Resource: resource::Resource>(
+        &self,
+        id: types::Id<Resource>,
+    ) -> Result<Resource, error::Error> {
+        self.read::<Resource>(&format!("{}/{id}", Resource::ENDPOINT), None)
+            .await
+    }
+
+    /// Retrieves (GET) Ruddr objects of any resource type by ids with at most `concurrency` requests in flight. Duplicate ids are retrieved once, and failures are returned per id instead of failing the batch.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let members = client.get_many::<member::Member>(
+    ///     time_entries.results.iter().map(|entry| entry.member.id.clone()),
+    ///     8,
+    /// ).await;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.get_many",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, concurrency)
+        )
+    )]
+    pub async fn get_many<Resource: resource::Resource>(
+        &self,
+        ids: impl IntoIterator<Item = types::Id<Resource>>,
+        concurrency: usize,
+    ) -> resource::Batch<Resource> {
+        let ids = resource::dedupe(ids);
+        log::debug!(
+            "retrieving {} objects from {}",
+            ids.len(),
+            Resource::ENDPOINT
+        );
+
+        // retrieve objects concurrently and collect results by id
+        let mut results = stream::iter(ids)
+            .map(|id| async move {
+                let result = self
+                    .read::<Resource>(&format!("{}/{id}", Resource::ENDPOINT), None)
+                    .await;
+                (id, result)
+            })
+            .buffer_unordered(concurrency.max(1));
+        let mut batch = resource::Batch::default();
+        while let Some((id, result)) = results.next().await {
+            batch.insert(id, result);
+        }
+        batch
+    }
+
+    /// Retrieves (GET) the first 100 Ruddr objects of any resource type by filters, and deserializes them to the corresponding list model struct.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let projects = client.list::<project::Project>(&ProjectsQuery {
+    ///     name_contains: Some("Portal"),
+    ///     ..Default::default()
+    /// }).await?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.list",
+            skip_all,
+            fields(endpoint = Resource::ENDPOINT, page_size = tracing::field::Empty, has_more = tracing::field::Empty)
+        )
+    )]
+    pub async fn list<Resource: resource::Resource>(
+        &self,
+        query: &Resource::Query<'_>,
+    ) -> Result<Resource::List, error::Error> {
+        use resource::{Page, Query};
+        let list = self
+            .read::<Resource::List>(Resource::ENDPOINT, Some(&query.params()))
+            .await?;
+        trace::record_page(list.results().len(), list.has_more());
+        Ok(list)
     }
 
     /// Retrieves (GET) a specific Ruddr generic object by id, or specific generic objects by filters, and deserializes it/them to the corresponding struct/structs.
-    /// This is public only to interface at the moment, but is abstract enough that assistance is super helpful to future me, and so documentation exists here.
+    /// This is the escape hatch for endpoints, parameters, and fields not yet modeled by this crate, and the endpoint is relative to the Ruddr API workspace path. An empty endpoint or unparseable URL returns `Error::InvalidRequest`.
     /// ```ignore
     /// let client = Client::new(Some("abcdefghi123456789"))?;
     /// let deser_response_read = client.read::<project::Project>(
@@ -71,31 +189,280 @@ impl Client {
     ///     Some("limit=100"),
     /// ).await?;
     /// ```
-    pub(crate) async fn read<Response: de::DeserializeOwned>(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.read",
+            skip_all,
+            fields(
+                endpoint = %endpoint,
+                params = trace::redact(params).unwrap_or_default(),
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                cached = false,
+            )
+        )
+    )]
+    pub async fn read<Response: de::DeserializeOwned>(
         &self,
         endpoint: &str,
         params: Option<&str>,
-    ) -> Result<Response, reqwest::Error> {
+    ) -> Result<Response, error::Error> {
         // construct and assign client request
-        let request = request::Request::new(endpoint, params);
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, params)?;
         log::debug!("request is {request:?}");
 
-        // retrieve object and deser
-        let response = request.get(&self.client).await?;
-        let deser = match response.error_for_status() {
-            // deser if successful
-            Ok(response) => response.json::<Response>().await?,
-            // provide information if failure
-            Err(error) => {
-                log::error!("request failed with status {:?}", error.status().unwrap());
-                log::error!("{error}");
-                return Err(error.without_url());
+        // use cached response if fresh, and otherwise revalidate it with its etag if stale
+        let stale = match self
+            .cache
+            .as_ref()
+            .map(|cache| cache.lookup(endpoint, params))
+        {
+            Some(cache::Lookup::Fresh(body)) => {
+                log::debug!("using cached response for {endpoint}");
+                trace::record_cached();
+                return deserialize(endpoint, reqwest::StatusCode::OK, &body);
             }
+            Some(cache::Lookup::Stale { etag, body }) => Some((etag, body)),
+            _ => None,
         };
+        let (etag, stale) = stale.unzip();
+
+        // retrieve object and deser
+        let response = request.get(&self.client, etag.as_deref()).await;
+        metrics::observe(
+            self.metrics.as_ref(),
+            "GET",
+            endpoint,
+            response.as_ref().ok().map(reqwest::Response::status),
+            start,
+        );
+        let response = response?;
+        let status = response.status();
+        trace::record_response(status, start);
+        let etag = etag_header(response.headers());
+        let body = response.text().await?;
+        let deser =
+            read_through::<Response>(self.cache(), endpoint, params, status, etag, body, stale)?;
 
         log::debug!("successful read from Ruddr API");
         Ok(deser)
     }
+
+    /// Retrieves (GET) a specific Ruddr generic object by id, or specific generic objects by filters, as raw JSON. This is useful for endpoints and fields not yet modeled by this crate.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let json = client.read_json("projects", Some("limit=100&nameContains=Portal")).await?;
+    /// let has_more = json["hasMore"].as_bool();
+    /// ```
+    pub async fn read_json(
+        &self,
+        endpoint: &str,
+        params: Option<&str>,
+    ) -> Result<serde_json::Value, error::Error> {
+        self.read::<serde_json::Value>(endpoint, params).await
+    }
+
+    /// Creates (POST) a Ruddr generic object from an input model, and deserializes the created object to the corresponding struct.
+    /// This is public only to interface at the moment, but is abstract enough that assistance is super helpful to future me, and so documentation exists here.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let deser_response = client.create::<project::ProjectInput, project::Project>(
+    ///     "projects",
+    ///     &project_input,
+    /// ).await?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.create",
+            skip_all,
+            fields(endpoint = %endpoint, status = tracing::field::Empty, latency_ms = tracing::field::Empty)
+        )
+    )]
+    pub(crate) async fn create<Body: Serialize, Response: de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Body,
+    ) -> Result<Response, error::Error> {
+        // construct and assign client request
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, None)?;
+        log::debug!("request is {request:?}");
+
+        // create object and deser
+        let response = request.post(&self.client, body).await;
+        metrics::observe(
+            self.metrics.as_ref(),
+            "POST",
+            endpoint,
+            response.as_ref().ok().map(reqwest::Response::status),
+            start,
+        );
+        let response = response?;
+        trace::record_response(response.status(), start);
+        let deser = Self::deserialize::<Response>(endpoint, response).await?;
+
+        // invalidate cached responses of the modified resource
+        if let Some(cache) = &self.cache {
+            cache.invalidate_endpoint(endpoint);
+        }
+
+        log::debug!("successful create with Ruddr API");
+        Ok(deser)
+    }
+
+    /// Updates (PATCH) a specific Ruddr generic object by id from an input model, and deserializes the updated object to the corresponding struct.
+    /// This is public only to interface at the moment, but is abstract enough that assistance is super helpful to future me, and so documentation exists here.
+    /// ```ignore
+    /// let client = Client::new(Some("abcdefghi123456789"))?;
+    /// let deser_response = client.update::<project::ProjectInput, project::Project>(
+    ///     "projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+    ///     &project_input,
+    /// ).await?;
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "ruddr.update",
+            skip_all,
+            fields(endpoint = %endpoint, status = tracing::field::Empty, latency_ms = tracing::field::Empty)
+        )
+    )]
+    pub(crate) async fn update<Body: Serialize, Response: de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &Body,
+    ) -> Result<Response, error::Error> {
+        // construct and assign client request
+        let start = Instant::now();
+        let request = request::Request::new(endpoint, None)?;
+        log::debug!("request is {request:?}");
+
+        // update object and deser
+        let response = request.patch(&self.client, body).await;
+        metrics::observe(
+            self.metrics.as_ref(),
+            "PATCH",
+            endpoint,
+            response.as_ref().ok().map(reqwest::Response::status),
+            start,
+        );
+        let response = response?;
+        trace::record_response(response.status(), start);
+        let deser = Self::deserialize::<Response>(endpoint, response).await?;
+
+        // invalidate cached responses of the modified resource
+        if let Some(cache) = &self.cache {
+            cache.invalidate_endpoint(endpoint);
+        }
+
+        log::debug!("successful update with Ruddr API");
+        Ok(deser)
+    }
+
+    // deserialize response body to the corresponding struct if successful, and provide information if failure
+    async fn deserialize<Response: de::DeserializeOwned>(
+        endpoint: &str,
+        response: reqwest::Response,
+    ) -> Result<Response, error::Error> {
+        let status = response.status();
+        let body = response.text().await?;
+        deserialize(endpoint, status, &body)
+    }
+}
+
+// construct default headers with json encoding and authentication from the token input through code or environment variable
+pub(crate) fn headers(
+    token: Option<&str>,
+) -> Result<reqwest::header::HeaderMap, Box<dyn std::error::Error>> {
+    // initialize headers and establish json encoding
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+    // determine authentication input method
+    let bearer_token = match token {
+        Some(token) => format!("Bearer {token}"),
+        None => match env::var("RUDDR_TOKEN") {
+            Ok(token) => format!("Bearer {token}"),
+            Err(_) => {
+                return Err(Box::from(
+                    "ruddr api token was not input through code or RUDDR_TOKEN environment variable",
+                ));
+            }
+        },
+    };
+    // establish authentication and mark as sensitive
+    let mut auth_token = reqwest::header::HeaderValue::from_str(&bearer_token)?;
+    auth_token.set_sensitive(true);
+    headers.insert(reqwest::header::AUTHORIZATION, auth_token);
+
+    log::debug!("built headers are {headers:?}");
+    Ok(headers)
+}
+
+// retrieve the etag validator of the response if present
+pub(crate) fn etag_header(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(reqwest::header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(String::from)
+}
+
+// deserialize a read response body with the cache if enabled i.e. use the stale cached body if revalidated, and cache the body if successful
+pub(crate) fn read_through<Response: de::DeserializeOwned>(
+    cache: Option<&cache::Cache>,
+    endpoint: &str,
+    params: Option<&str>,
+    status: reqwest::StatusCode,
+    etag: Option<String>,
+    body: String,
+    stale: Option<String>,
+) -> Result<Response, error::Error> {
+    let Some(cache) = cache else {
+        return deserialize(endpoint, status, &body);
+    };
+
+    // cached response was revalidated, and the stale body is used even if the entry was invalidated during the request
+    if status == reqwest::StatusCode::NOT_MODIFIED
+        && let Some(body) = stale
+    {
+        log::debug!("using revalidated cached response for {endpoint}");
+        cache.revalidate(endpoint, params);
+        return deserialize(endpoint, reqwest::StatusCode::OK, &body);
+    }
+
+    // cache response only if it was successfully deserialized
+    let deser = deserialize(endpoint, status, &body)?;
+    cache.store(endpoint, params, body, etag);
+    Ok(deser)
+}
+
+// deserialize response body to the corresponding struct if successful, and provide information if failure
+pub(crate) fn deserialize<Response: de::DeserializeOwned>(
+    endpoint: &str,
+    status: reqwest::StatusCode,
+    body: &str,
+) -> Result<Response, error::Error> {
+    // provide information from the error payload if failure
+    if !status.is_success() {
+        let error = error::ApiError::new(status, body);
+        log::error!("request failed with status {status:?} for endpoint {endpoint}");
+        log::error!("{error}");
+        return Err(error::Error::from(error));
+    }
+
+    // deser if successful and track path to value on failure
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer).map_err(|error| {
+        let error = error::DeserializeError::new(endpoint, body, error);
+        log::error!("response deserialization failed for endpoint {endpoint}");
+        log::error!("{error}");
+        error::Error::from(error)
+    })
 }
 
 #[cfg(test)]
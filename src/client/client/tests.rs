@@ -1,5 +1,5 @@
 use super::*;
-use crate::model::project;
+use crate::model::{project, role, types};
 
 #[tokio::test]
 async fn test_client_new() {
@@ -39,6 +39,20 @@ async fn test_client_read() {
     assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
 }
 
+#[tokio::test]
+async fn test_client_read_empty_endpoint() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert!(matches!(
+        client.read::<project::Project>("", None).await,
+        Err(error::Error::InvalidRequest(_))
+    ));
+    assert!(matches!(
+        client.read_json("", Some("limit=100")).await,
+        Err(error::Error::InvalidRequest(_))
+    ));
+}
+
 #[tokio::test]
 async fn test_client_list() {
     let client = Client::new(Some("abcdefghi123456789"))
@@ -49,3 +63,187 @@ async fn test_client_list() {
         .expect_err("list did not fail on auth");
     assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
 }
+
+#[tokio::test]
+async fn test_client_read_json() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    let response = client
+        .read_json("projects", Some("limit=100"))
+        .await
+        .expect_err("raw json read did not fail on auth");
+    assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_client_create() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    let response = client
+        .create::<project::ProjectInput, project::Project>(
+            "projects",
+            &project::ProjectInput {
+                name: Some(String::from("Vendor Portal")),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("create did not fail on auth");
+    assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_client_update() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    let response = client
+        .update::<project::ProjectInput, project::Project>(
+            "projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+            &project::ProjectInput {
+                name: Some(String::from("Vendor Portal")),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("update did not fail on auth");
+    assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_client_get() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    let response = client
+        .get::<project::Project>(
+            types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                .expect("uuid conversion failed"),
+        )
+        .await
+        .expect_err("get did not fail on auth");
+    assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_client_list_resource() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    let response = client
+        .list::<project::Project>(&crate::interface::project::ProjectsQuery::default())
+        .await
+        .expect_err("list did not fail on auth");
+    assert_eq!(response.status(), Some(reqwest::StatusCode::UNAUTHORIZED));
+}
+
+#[tokio::test]
+async fn test_client_read_cached() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed")
+        .with_cache(cache::Cache::new(std::time::Duration::from_secs(60)));
+    let cache = client.cache().expect("cache was not enabled");
+    cache.store(
+        "projects",
+        Some("limit=100"),
+        String::from(r#"{"results": [], "hasMore": false}"#),
+        None,
+    );
+    assert_eq!(
+        client
+            .read_json("projects", Some("limit=100"))
+            .await
+            .expect("cached read failed"),
+        serde_json::json!({"results": [], "hasMore": false}),
+    );
+    assert!(
+        !client
+            .read::<project::Projects>("projects", Some("limit=100"))
+            .await
+            .expect("cached read failed")
+            .has_more
+    );
+}
+
+#[test]
+fn test_client_read_through() {
+    let cache = cache::Cache::new(std::time::Duration::ZERO);
+    cache.store(
+        "projects",
+        None,
+        String::from(r#"{"results": [], "hasMore": false}"#),
+        Some(String::from("\"v1\"")),
+    );
+    let stale = match cache.lookup("projects", None) {
+        cache::Lookup::Stale { body, .. } => Some(body),
+        lookup => panic!("cached response was not stale: {lookup:?}"),
+    };
+
+    // entry invalidated while the conditional request was in flight
+    cache.invalidate("projects", None);
+    assert!(
+        !read_through::<project::Projects>(
+            Some(&cache),
+            "projects",
+            None,
+            reqwest::StatusCode::NOT_MODIFIED,
+            None,
+            String::new(),
+            stale,
+        )
+        .expect("revalidated read failed")
+        .has_more
+    );
+    assert_eq!(cache.lookup("projects", None), cache::Lookup::Miss);
+
+    // not modified without a stale body is still a failure
+    assert!(
+        read_through::<project::Projects>(
+            Some(&cache),
+            "projects",
+            None,
+            reqwest::StatusCode::NOT_MODIFIED,
+            None,
+            String::new(),
+            None,
+        )
+        .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_client_get_many() {
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed")
+        .with_cache(cache::Cache::new(std::time::Duration::from_secs(60)));
+    let cached = "7ad5a34a-07b7-48e9-a760-bd220d52e354";
+    let uncached = "ec5543de-3b0f-47a0-b8ef-a6e18dc4b885";
+    let id = |uuid| types::Id::<role::Role>::try_from(uuid).expect("uuid conversion failed");
+    client.cache().expect("cache was not enabled").store(
+        &format!("project-roles/{cached}"),
+        None,
+        format!(
+            r#"{{"id": "{cached}", "name": "Project Manager", "isActive": true, "isBillable": true, "rate": 100, "createdAt": "2022-03-02T17:40:03.633Z", "project": {{"id": "095e0780-48bf-472c-8deb-2fc3ebc7d90c", "name": "Vendor Portal", "client": {{"id": "4cacdf11-71d1-4fbb-90ee-b091803581b0", "name": "Joe's Shop"}}}}, "discipline": null, "practice": null, "location": null, "budget": null, "monthlyBudget": null}}"#
+        ),
+        None,
+    );
+    let batch = client
+        .get_many::<role::Role>([id(cached), id(uncached), id(cached)], 2)
+        .await;
+    assert_eq!(batch.found.len(), 1);
+    assert_eq!(batch.found[&id(cached)].name, "Project Manager");
+    assert_eq!(batch.errors.len(), 1);
+    assert!(batch.errors.contains_key(&id(uncached)));
+}
+
+#[tokio::test]
+async fn test_client_metrics() {
+    let recorder = std::sync::Arc::new(metrics::InMemoryRecorder::default());
+    let client = Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed")
+        .with_metrics(recorder.clone());
+    let _ = client
+        .read_json("projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c", None)
+        .await;
+    assert_eq!(
+        recorder.snapshot()[&("GET", String::from("projects/{id}"))].requests,
+        1
+    );
+}
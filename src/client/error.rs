@@ -0,0 +1,264 @@
+//! # Error
+//!
+//! `client::error` defines the error types returned by the Ruddr API client and interfaces.
+use std::fmt;
+
+use crate::model::shared;
+
+// maximum length in bytes of the response body snippet on either side of a deserialization failure
+const SNIPPET_RADIUS: usize = 100;
+
+/// Aggregation of error types for client operations.
+#[derive(Debug)]
+pub enum Error {
+    /// Request could not be executed, or the response could not be received.
+    Request(reqwest::Error),
+    /// Request could not be constructed from the endpoint and query e.g. an empty endpoint.
+    InvalidRequest(String),
+    /// Response status was unsuccessful, and the Ruddr API described the failure in the response body.
+    Api(ApiError),
+    /// Response body could not be deserialized to the corresponding model struct.
+    Deserialize(DeserializeError),
+    /// Input model failed client-side validation, and no request was executed.
+    Validation(shared::ValidationError),
+}
+
+impl Error {
+    /// Returns the response status code if the error originated from an unsuccessful response.
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Error::Request(error) => error.status(),
+            Error::Api(error) => Some(error.status),
+            Error::InvalidRequest(_) | Error::Deserialize(_) | Error::Validation(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Request(error) => write!(format, "{error}"),
+            Error::InvalidRequest(message) => write!(format, "invalid request: {message}"),
+            Error::Api(error) => write!(format, "{error}"),
+            Error::Deserialize(error) => write!(format, "{error}"),
+            Error::Validation(error) => write!(format, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Validation(error) => Some(error),
+            Error::InvalidRequest(_) | Error::Api(_) | Error::Deserialize(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+impl From<ApiError> for Error {
+    fn from(error: ApiError) -> Self {
+        Error::Api(error)
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(error: DeserializeError) -> Self {
+        Error::Deserialize(error)
+    }
+}
+
+impl From<shared::ValidationError> for Error {
+    fn from(error: shared::ValidationError) -> Self {
+        Error::Validation(error)
+    }
+}
+
+/// Category of unsuccessful response status returned by the Ruddr API.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ApiErrorKind {
+    /// 400: request parameters or body failed validation.
+    BadRequest,
+    /// 401: API token is missing, invalid, or revoked.
+    Unauthorized,
+    /// 403: API token lacks permission for the operation.
+    Forbidden,
+    /// 404: object or endpoint does not exist.
+    NotFound,
+    /// 429: rate limit was exceeded.
+    TooManyRequests,
+    /// 5xx: Ruddr API failed to process the request.
+    Server,
+    /// Any other unsuccessful status.
+    Other,
+}
+
+/// Unsuccessful response from the Ruddr API with the failure described in the response body.
+#[derive(Debug)]
+pub struct ApiError {
+    /// Response status code.
+    pub status: reqwest::StatusCode,
+    /// Description of the failure returned by the Ruddr API, or the canonical status reason if none was returned. A response body which is not a JSON error payload is truncated.
+    pub message: String,
+    /// Additional information about the failure e.g. the validation failures for specific fields.
+    pub details: Option<serde_json::Value>,
+}
+
+impl ApiError {
+    // construct from the response status and body
+    pub(crate) fn new(status: reqwest::StatusCode, body: &str) -> Self {
+        let reason = status.canonical_reason().unwrap_or("unknown status");
+        match serde_json::from_str::<serde_json::Value>(body) {
+            // ruddr error payload with message as string or nested object
+            Ok(serde_json::Value::Object(mut payload)) => {
+                let message = match payload.remove("message") {
+                    Some(serde_json::Value::String(message)) => Some(message),
+                    _ => match payload.remove("error") {
+                        Some(serde_json::Value::String(message)) => Some(message),
+                        Some(serde_json::Value::Object(mut error)) => match error.remove("message")
+                        {
+                            Some(serde_json::Value::String(message)) => Some(message),
+                            _ => None,
+                        },
+                        _ => None,
+                    },
+                };
+                let details = payload
+                    .remove("details")
+                    .or_else(|| payload.remove("errors"));
+                Self {
+                    status,
+                    message: message.unwrap_or_else(|| String::from(reason)),
+                    details,
+                }
+            }
+            // anything else is retained as the truncated message if present e.g. a proxy error page
+            _ => Self {
+                status,
+                message: match body.trim() {
+                    "" => String::from(reason),
+                    body => snippet(body, 1, 1),
+                },
+                details: None,
+            },
+        }
+    }
+
+    /// Returns the category of the response status so that callers can react to it.
+    /// ```ignore
+    /// if let Err(Error::Api(error)) = project(&client, id).await {
+    ///     match error.kind() {
+    ///         ApiErrorKind::NotFound => println!("project does not exist"),
+    ///         ApiErrorKind::TooManyRequests => println!("retry later"),
+    ///         _ => println!("{error}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn kind(&self) -> ApiErrorKind {
+        match self.status.as_u16() {
+            400 => ApiErrorKind::BadRequest,
+            401 => ApiErrorKind::Unauthorized,
+            403 => ApiErrorKind::Forbidden,
+            404 => ApiErrorKind::NotFound,
+            429 => ApiErrorKind::TooManyRequests,
+            500..=599 => ApiErrorKind::Server,
+            _ => ApiErrorKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            format,
+            "ruddr api request failed with status {}: {}",
+            self.status, self.message
+        )?;
+        if let Some(details) = &self.details {
+            write!(format, " ({details})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Response body deserialization failure with the path to the offending JSON value e.g. `results[37].clientStatusId`.
+#[derive(Debug)]
+pub struct DeserializeError {
+    /// Ruddr API endpoint of the request.
+    pub endpoint: String,
+    /// Path to the JSON value which could not be deserialized.
+    pub path: String,
+    /// Description of the deserialization failure.
+    pub message: String,
+    /// Truncated response body surrounding the deserialization failure.
+    pub snippet: String,
+}
+
+impl DeserializeError {
+    // construct from the path tracking error with the endpoint and response body
+    pub(crate) fn new(
+        endpoint: &str,
+        body: &str,
+        error: serde_path_to_error::Error<serde_json::Error>,
+    ) -> Self {
+        let snippet = snippet(body, error.inner().line(), error.inner().column());
+        Self {
+            endpoint: String::from(endpoint),
+            path: error.path().to_string(),
+            message: error.inner().to_string(),
+            snippet,
+        }
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            format,
+            "{}: {} (endpoint: {}, body: {})",
+            self.path, self.message, self.endpoint, self.snippet
+        )
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+// truncate body to the bytes surrounding the one-based line and column of the failure, and mark the truncations with ellipses
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    // convert line and column to byte offset
+    let offset = body
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column.saturating_sub(1);
+    let offset = offset.min(body.len());
+
+    // widen bounds to char boundaries
+    let mut start = offset.saturating_sub(SNIPPET_RADIUS);
+    while !body.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (offset + SNIPPET_RADIUS).min(body.len());
+    while !body.is_char_boundary(end) {
+        end += 1;
+    }
+
+    format!(
+        "{}{}{}",
+        if start > 0 { "..." } else { "" },
+        &body[start..end],
+        if end < body.len() { "..." } else { "" },
+    )
+}
+
+#[cfg(test)]
+mod tests;
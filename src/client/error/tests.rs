@@ -0,0 +1,106 @@
+use super::*;
+use crate::model::time;
+
+#[test]
+fn test_deserialize_error() {
+    let body = r#"{"results": [{"id": "4497fa99-27a4-4509-9748-83e4399296e3", "clientStatusId": 5}], "hasMore": false}"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    let error = DeserializeError::new(
+        "time-entries",
+        body,
+        serde_path_to_error::deserialize::<_, time::TimeEntries>(deserializer)
+            .expect_err("invalid time entries did not fail deserialization"),
+    );
+    assert_eq!(error.endpoint, "time-entries");
+    assert_eq!(error.path, "results[0].clientStatusId");
+    assert_eq!(error.snippet, body);
+    let error = Error::from(error);
+    assert_eq!(error.status(), None);
+    assert!(error.to_string().contains("endpoint: time-entries"));
+}
+
+#[test]
+fn test_snippet() {
+    assert_eq!(snippet("{}", 1, 2), "{}");
+    let body = format!("{{\"results\": [{}]}}", "1,".repeat(200));
+    let snippet = snippet(&body, 1, 200);
+    assert!(snippet.starts_with("...") && snippet.ends_with("..."));
+    assert_eq!(snippet.len(), 2 * SNIPPET_RADIUS + 6);
+    assert_eq!(
+        super::snippet("{\n\"a\": \"é\"\n}", 2, 7),
+        "{\n\"a\": \"é\"\n}"
+    );
+}
+
+#[test]
+fn test_api_error() {
+    let error = ApiError::new(
+        reqwest::StatusCode::BAD_REQUEST,
+        r#"{"message": "Validation failed", "details": {"name": "is required"}}"#,
+    );
+    assert_eq!(error.kind(), ApiErrorKind::BadRequest);
+    assert_eq!(error.message, "Validation failed");
+    assert_eq!(
+        error.details,
+        Some(serde_json::json!({"name": "is required"}))
+    );
+    assert_eq!(
+        error.to_string(),
+        r#"ruddr api request failed with status 400 Bad Request: Validation failed ({"name":"is required"})"#,
+    );
+
+    let error = ApiError::new(
+        reqwest::StatusCode::FORBIDDEN,
+        r#"{"error": {"message": "Insufficient permissions"}}"#,
+    );
+    assert_eq!(error.kind(), ApiErrorKind::Forbidden);
+    assert_eq!(error.message, "Insufficient permissions");
+    assert_eq!(error.details, None);
+
+    let error = ApiError::new(reqwest::StatusCode::UNAUTHORIZED, "");
+    assert_eq!(error.kind(), ApiErrorKind::Unauthorized);
+    assert_eq!(error.message, "Unauthorized");
+    assert_eq!(
+        Error::from(error).status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED)
+    );
+
+    let error = ApiError::new(reqwest::StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>");
+    assert_eq!(error.kind(), ApiErrorKind::Server);
+    assert_eq!(error.message, "<html>Bad Gateway</html>");
+
+    // lengthy non-json body is truncated
+    let body = format!("<html>{}</html>", "a".repeat(500));
+    let error = ApiError::new(reqwest::StatusCode::BAD_GATEWAY, &body);
+    assert_eq!(error.message, format!("{}...", &body[..SNIPPET_RADIUS]));
+}
+
+#[test]
+fn test_api_error_kind() {
+    for (status, kind) in [
+        (404, ApiErrorKind::NotFound),
+        (429, ApiErrorKind::TooManyRequests),
+        (500, ApiErrorKind::Server),
+        (503, ApiErrorKind::Server),
+        (409, ApiErrorKind::Other),
+    ] {
+        assert_eq!(
+            ApiError::new(
+                reqwest::StatusCode::from_u16(status).expect("invalid status code"),
+                "{}"
+            )
+            .kind(),
+            kind,
+        );
+    }
+}
+
+#[test]
+fn test_validation_error() {
+    let error = Error::from(shared::ValidationError(String::from(
+        "name is required to create a client",
+    )));
+    assert!(matches!(error, Error::Validation(_)));
+    assert_eq!(error.status(), None);
+    assert_eq!(error.to_string(), "name is required to create a client");
+}
@@ -0,0 +1,140 @@
+//! # Metrics
+//!
+//! `client::metrics` consists of the hook invoked by clients around each request to the Ruddr API, so that request counts, latencies, error rates, and rate limiting can be bridged to a metrics system e.g. Prometheus or StatsD.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::model::types;
+
+/// Measurements of a single request to the Ruddr API.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics<'request> {
+    /// HTTP method of the request e.g. `GET`.
+    pub method: &'static str,
+    /// Endpoint with ids replaced by a placeholder e.g. `projects/{id}`, so that it is suitable as a low cardinality label.
+    pub endpoint: &'request str,
+    /// Response status code, or `None` if no response was received.
+    pub status: Option<reqwest::StatusCode>,
+    /// Time elapsed from sending the request until receiving the response or failure.
+    pub duration: Duration,
+}
+
+/// Hook invoked by a `Client` after each request to the Ruddr API. Responses served from the cache without a request are not recorded.
+/// ```ignore
+/// #[derive(Debug)]
+/// struct StatsdRecorder(statsd::Client);
+///
+/// impl MetricsRecorder for StatsdRecorder {
+///     fn record(&self, metrics: &RequestMetrics) {
+///         self.0.timer(&format!("ruddr.{}", metrics.endpoint), metrics.duration.as_millis() as f64);
+///     }
+/// }
+///
+/// let client = Client::new(Some("abcdefghi123456789"))?.with_metrics(StatsdRecorder(statsd));
+/// ```
+pub trait MetricsRecorder: Send + Sync + fmt::Debug {
+    /// Record the measurements of a completed request.
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+/// Recorder which discards all measurements. This is the default for a `Client`.
+#[derive(Debug, Default)]
+pub struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {
+    fn record(&self, _metrics: &RequestMetrics) {}
+}
+
+/// Aggregated measurements of the requests to an endpoint.
+#[derive(PartialEq, Eq, Debug, Default, Clone)]
+pub struct EndpointMetrics {
+    /// Number of requests.
+    pub requests: u64,
+    /// Number of requests without a successful or not modified response status, including those without a response.
+    pub errors: u64,
+    /// Number of requests rejected with status 429 by the rate limit.
+    pub rate_limited: u64,
+    /// Sum of the durations of the requests.
+    pub total_duration: Duration,
+}
+
+/// Example recorder which aggregates measurements in memory by method and endpoint template.
+/// ```ignore
+/// let recorder = Arc::new(InMemoryRecorder::default());
+/// let client = Client::new(Some("abcdefghi123456789"))?.with_metrics(recorder.clone());
+/// // requests with client
+/// for ((method, endpoint), metrics) in recorder.snapshot() {
+///     println!("{method} {endpoint}: {} requests, {} errors", metrics.requests, metrics.errors);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryRecorder {
+    endpoints: Mutex<BTreeMap<(&'static str, String), EndpointMetrics>>,
+}
+
+impl InMemoryRecorder {
+    /// Returns a copy of the aggregated measurements by method and endpoint template.
+    pub fn snapshot(&self) -> BTreeMap<(&'static str, String), EndpointMetrics> {
+        self.endpoints.lock().unwrap().clone()
+    }
+}
+
+impl MetricsRecorder for InMemoryRecorder {
+    fn record(&self, metrics: &RequestMetrics) {
+        let mut endpoints = self.endpoints.lock().unwrap();
+        let endpoint = endpoints
+            .entry((metrics.method, String::from(metrics.endpoint)))
+            .or_default();
+        endpoint.requests += 1;
+        endpoint.total_duration += metrics.duration;
+        // not modified is a successful revalidation of a cached response
+        if !metrics.status.is_some_and(|status| {
+            status.is_success() || status == reqwest::StatusCode::NOT_MODIFIED
+        }) {
+            endpoint.errors += 1;
+        }
+        if metrics.status == Some(reqwest::StatusCode::TOO_MANY_REQUESTS) {
+            endpoint.rate_limited += 1;
+        }
+    }
+}
+
+impl<Recorder: MetricsRecorder + ?Sized> MetricsRecorder for std::sync::Arc<Recorder> {
+    fn record(&self, metrics: &RequestMetrics) {
+        (**self).record(metrics)
+    }
+}
+
+// record the measurements of a request with the endpoint converted to its template
+pub(crate) fn observe(
+    recorder: &dyn MetricsRecorder,
+    method: &'static str,
+    endpoint: &str,
+    status: Option<reqwest::StatusCode>,
+    start: Instant,
+) {
+    let endpoint = template(endpoint);
+    recorder.record(&RequestMetrics {
+        method,
+        endpoint: &endpoint,
+        status,
+        duration: start.elapsed(),
+    });
+}
+
+// replace the ids in the endpoint with a placeholder e.g. `projects/{id}`
+fn template(endpoint: &str) -> String {
+    endpoint
+        .split('/')
+        .map(|segment| match types::UUID::try_from(segment) {
+            Ok(_) => "{id}",
+            Err(_) => segment,
+        })
+        .collect::<Vec<&str>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests;
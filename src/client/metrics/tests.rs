@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn test_template() {
+    assert_eq!(template("projects"), "projects");
+    assert_eq!(
+        template("projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c"),
+        "projects/{id}"
+    );
+}
+
+#[test]
+fn test_in_memory_recorder() {
+    let recorder = InMemoryRecorder::default();
+    for status in [
+        Some(reqwest::StatusCode::OK),
+        Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        Some(reqwest::StatusCode::NOT_MODIFIED),
+        None,
+    ] {
+        observe(
+            &recorder,
+            "GET",
+            "projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c",
+            status,
+            Instant::now(),
+        );
+    }
+    NoopRecorder.record(&RequestMetrics {
+        method: "GET",
+        endpoint: "projects",
+        status: None,
+        duration: Duration::ZERO,
+    });
+
+    let snapshot = recorder.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    let metrics = &snapshot[&("GET", String::from("projects/{id}"))];
+    assert_eq!(metrics.requests, 4);
+    assert_eq!(metrics.errors, 2);
+    assert_eq!(metrics.rate_limited, 1);
+}
@@ -3,48 +3,138 @@
 //! `client::request` consists of functions for constructing and executing requests against the Ruddr API.
 use log;
 use reqwest::Url;
+use serde::Serialize;
+
+use super::error;
 
 // request struct for composing request structures
 #[derive(Debug)]
-pub(super) struct Request {
+pub(crate) struct Request {
     url: Url,
 }
 
 impl Request {
     // request constructor with endpoint and params
-    pub(super) fn new(endpoint: &str, params: Option<&str>) -> Self {
+    pub(crate) fn new(endpoint: &str, params: Option<&str>) -> Result<Self, error::Error> {
         // validate endpoint is not empty
-        assert!(!endpoint.is_empty(), "endpoint must not be empty");
+        if endpoint.is_empty() {
+            return Err(error::Error::InvalidRequest(String::from(
+                "endpoint must not be empty",
+            )));
+        }
 
         // prefix params with "?" char if they are specified
         let url = match params {
             Some(params) => {
                 log::debug!("request endpoint is {endpoint} and params is {params}");
-                Url::parse(&format!(
-                    "https://www.ruddr.io/api/workspace/{endpoint}?{params}"
-                ))
-                .unwrap()
+                format!("https://www.ruddr.io/api/workspace/{endpoint}?{params}")
             }
             None => {
                 log::debug!("request endpoint is {endpoint} and params is empty");
-                Url::parse(&format!("https://www.ruddr.io/api/workspace/{endpoint}")).unwrap()
+                format!("https://www.ruddr.io/api/workspace/{endpoint}")
             }
         };
-        Self { url }
+        let url = Url::parse(&url)
+            .map_err(|error| error::Error::InvalidRequest(format!("invalid url {url}: {error}")))?;
+        Ok(Self { url })
     }
 
-    // execute get request with client
+    // execute get request with client, and conditionally with an etag validator
     pub(super) async fn get(
         &self,
         client: &reqwest::Client,
+        etag: Option<&str>,
     ) -> Result<reqwest::Response, reqwest::Error> {
         // execute request and receive response
         log::debug!("initiating GET request at {}", self.url);
-        let response = client.get(self.url.as_str()).send().await?;
+        let mut request = client.get(self.url.as_str());
+        if let Some(etag) = etag {
+            log::debug!("revalidating cached response with etag {etag}");
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        let response = request.send().await?;
 
         log::debug!("response received for GET request");
         Ok(response)
     }
+
+    // execute post request with client and json body
+    pub(super) async fn post<Body: Serialize>(
+        &self,
+        client: &reqwest::Client,
+        body: &Body,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        // execute request and receive response
+        log::debug!("initiating POST request at {}", self.url);
+        let response = client.post(self.url.as_str()).json(body).send().await?;
+
+        log::debug!("response received for POST request");
+        Ok(response)
+    }
+
+    // execute patch request with client and json body
+    pub(super) async fn patch<Body: Serialize>(
+        &self,
+        client: &reqwest::Client,
+        body: &Body,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        // execute request and receive response
+        log::debug!("initiating PATCH request at {}", self.url);
+        let response = client.patch(self.url.as_str()).json(body).send().await?;
+
+        log::debug!("response received for PATCH request");
+        Ok(response)
+    }
+
+    // execute blocking get request with client, and conditionally with an etag validator
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking_get(
+        &self,
+        client: &reqwest::blocking::Client,
+        etag: Option<&str>,
+    ) -> Result<reqwest::blocking::Response, reqwest::Error> {
+        // execute request and receive response
+        log::debug!("initiating blocking GET request at {}", self.url);
+        let mut request = client.get(self.url.as_str());
+        if let Some(etag) = etag {
+            log::debug!("revalidating cached response with etag {etag}");
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        let response = request.send()?;
+
+        log::debug!("response received for blocking GET request");
+        Ok(response)
+    }
+
+    // execute blocking post request with client and json body
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking_post<Body: Serialize>(
+        &self,
+        client: &reqwest::blocking::Client,
+        body: &Body,
+    ) -> Result<reqwest::blocking::Response, reqwest::Error> {
+        // execute request and receive response
+        log::debug!("initiating blocking POST request at {}", self.url);
+        let response = client.post(self.url.as_str()).json(body).send()?;
+
+        log::debug!("response received for blocking POST request");
+        Ok(response)
+    }
+
+    // execute blocking patch request with client and json body
+    #[cfg(feature = "blocking")]
+    pub(crate) fn blocking_patch<Body: Serialize>(
+        &self,
+        client: &reqwest::blocking::Client,
+        body: &Body,
+    ) -> Result<reqwest::blocking::Response, reqwest::Error> {
+        // execute request and receive response
+        log::debug!("initiating blocking PATCH request at {}", self.url);
+        let response = client.patch(self.url.as_str()).json(body).send()?;
+
+        log::debug!("response received for blocking PATCH request");
+        Ok(response)
+    }
 }
 
 #[cfg(test)]
@@ -3,7 +3,9 @@ use super::*;
 #[test]
 fn test_request_new_params() {
     assert_eq!(
-        Request::new("endpoint", Some("params")).url,
+        Request::new("endpoint", Some("params"))
+            .expect("request could not be constructed")
+            .url,
         Url::parse("https://www.ruddr.io/api/workspace/endpoint?params")
             .expect("failed to parse URL")
     )
@@ -12,15 +14,21 @@ fn test_request_new_params() {
 #[test]
 fn test_request_new_empty_params() {
     assert_eq!(
-        Request::new("endpoint", None).url,
+        Request::new("endpoint", None)
+            .expect("request could not be constructed")
+            .url,
         Url::parse("https://www.ruddr.io/api/workspace/endpoint").expect("failed to parse URL")
     )
 }
 
 #[test]
-#[should_panic(expected = "endpoint must not be empty")]
 fn test_request_new_empty_endpoint_error() {
-    Request::new("", Some("params"));
+    let error = Request::new("", Some("params")).expect_err("empty endpoint was accepted");
+    assert!(matches!(error, error::Error::InvalidRequest(_)));
+    assert_eq!(
+        error.to_string(),
+        "invalid request: endpoint must not be empty"
+    );
 }
 
 #[tokio::test]
@@ -28,9 +36,10 @@ async fn test_request_get() {
     let client = reqwest::Client::builder()
         .build()
         .expect("client with env token could not be constructed");
-    let request = Request::new("projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c", None);
+    let request = Request::new("projects/095e0780-48bf-472c-8deb-2fc3ebc7d90c", None)
+        .expect("request could not be constructed");
     let response = request
-        .get(&client)
+        .get(&client, None)
         .await
         .expect("request transmission failed to receive a response");
     println!("response: {:?}", response);
@@ -40,3 +49,38 @@ async fn test_request_get() {
         "the response did not return expected 401 status",
     )
 }
+
+#[tokio::test]
+async fn test_request_post() {
+    let client = reqwest::Client::builder()
+        .build()
+        .expect("client with env token could not be constructed");
+    let request = Request::new("clients", None).expect("request could not be constructed");
+    let response = request
+        .post(&client, &serde_json::json!({"name": "Joe's Shop"}))
+        .await
+        .expect("request transmission failed to receive a response");
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::UNAUTHORIZED,
+        "the response did not return expected 401 status",
+    )
+}
+
+#[tokio::test]
+async fn test_request_patch() {
+    let client = reqwest::Client::builder()
+        .build()
+        .expect("client with env token could not be constructed");
+    let request = Request::new("clients/4cacdf11-71d1-4fbb-90ee-b091803581b0", None)
+        .expect("request could not be constructed");
+    let response = request
+        .patch(&client, &serde_json::json!({"name": "Joe's Shop"}))
+        .await
+        .expect("request transmission failed to receive a response");
+    assert_eq!(
+        response.status(),
+        reqwest::StatusCode::UNAUTHORIZED,
+        "the response did not return expected 401 status",
+    )
+}
@@ -0,0 +1,100 @@
+//! # Resource
+//!
+//! `client::resource` consists of traits associating the Ruddr API models with their endpoints, so that Read and List operations can be performed generically over any resource type with `client::Client::get` and `client::Client::list`.
+use serde::de;
+use std::collections::{HashMap, HashSet};
+
+use super::error;
+use crate::model::types;
+
+/// Ruddr API object with Read and List operations. This is implemented for every model used with Read operations.
+/// ```ignore
+/// // retrieve every result of any resource type
+/// async fn export<R: Resource>(client: &Client, query: &R::Query<'_>) -> Result<Vec<R>, error::Error> {
+///     Ok(client.list::<R>(query).await?.into_results())
+/// }
+/// ```
+pub trait Resource: de::DeserializeOwned {
+    /// Ruddr API endpoint path for the resource e.g. `time-entries`.
+    const ENDPOINT: &'static str;
+    /// Model for the resource used with List operations.
+    type List: Page<Self>;
+    /// Query filters for the resource used with List operations.
+    type Query<'query>: Query;
+
+    /// Returns the id of the resource.
+    fn id(&self) -> &types::Id<Self>;
+}
+
+/// Query filters for List operations on a Ruddr API object.
+pub trait Query {
+    /// Returns the query parameters of the filters with their Ruddr query names. Unspecified filters are omitted.
+    fn params(&self) -> String;
+}
+
+/// Page of results returned by List operations on a Ruddr API object.
+pub trait Page<Item>: de::DeserializeOwned {
+    /// Returns the results of the page.
+    fn results(&self) -> &[Item];
+    /// Returns the results of the page by value.
+    fn into_results(self) -> Vec<Item>;
+    /// Returns whether more results exist after this page.
+    fn has_more(&self) -> bool;
+}
+
+/// Results of a batch Read operation by ids, where the failure to retrieve one object does not fail the batch.
+/// ```ignore
+/// let batch = client.get_many::<member::Member>(ids, 8).await;
+/// for (id, error) in &batch.errors {
+///     log::warn!("member {id} could not be retrieved: {error}");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Batch<Resource> {
+    /// Retrieved objects by id.
+    pub found: HashMap<types::Id<Resource>, Resource>,
+    /// Failures by id e.g. objects which do not exist.
+    pub errors: HashMap<types::Id<Resource>, error::Error>,
+}
+
+impl<Resource> Default for Batch<Resource> {
+    fn default() -> Self {
+        Self {
+            found: HashMap::new(),
+            errors: HashMap::new(),
+        }
+    }
+}
+
+impl<Resource> Batch<Resource> {
+    // record the result of retrieving the object by id
+    pub(crate) fn insert(
+        &mut self,
+        id: types::Id<Resource>,
+        result: Result<Resource, error::Error>,
+    ) {
+        match result {
+            Ok(resource) => {
+                self.found.insert(id, resource);
+            }
+            Err(error) => {
+                self.errors.insert(id, error);
+            }
+        }
+    }
+}
+
+// percent-encode a free-text filter value for the query string e.g. `Joe's Shop` as `Joe%27s+Shop`
+pub(crate) fn encode(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+// deduplicate ids
+pub(crate) fn dedupe<Resource>(
+    ids: impl IntoIterator<Item = types::Id<Resource>>,
+) -> HashSet<types::Id<Resource>> {
+    ids.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests;
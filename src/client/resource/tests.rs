@@ -0,0 +1,52 @@
+use super::*;
+
+struct Resource;
+
+// construct id from literal
+fn id<Resource>(uuid: &str) -> types::Id<Resource> {
+    types::Id::try_from(uuid).expect("uuid conversion failed")
+}
+
+#[test]
+fn test_encode() {
+    assert_eq!(encode("portal"), "portal");
+    assert_eq!(encode("Joe's Shop & Co"), "Joe%27s+Shop+%26+Co");
+    assert_eq!(encode("foo@bar.com"), "foo%40bar.com");
+    assert_eq!(encode("a=b#c"), "a%3Db%23c");
+}
+
+#[test]
+fn test_dedupe() {
+    let ids = dedupe::<Resource>([
+        id("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885"),
+        id("095e0780-48bf-472c-8deb-2fc3ebc7d90c"),
+        id("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885"),
+    ]);
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&id("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")));
+    assert!(ids.contains(&id("095e0780-48bf-472c-8deb-2fc3ebc7d90c")));
+}
+
+#[test]
+fn test_batch_insert() {
+    let mut batch = Batch::<String>::default();
+    batch.insert(
+        id("095e0780-48bf-472c-8deb-2fc3ebc7d90c"),
+        Ok(String::from("found")),
+    );
+    batch.insert(
+        id("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885"),
+        Err(error::Error::from(error::ApiError::new(
+            reqwest::StatusCode::NOT_FOUND,
+            "",
+        ))),
+    );
+    assert_eq!(
+        batch.found[&id("095e0780-48bf-472c-8deb-2fc3ebc7d90c")],
+        "found"
+    );
+    assert_eq!(
+        batch.errors[&id("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")].status(),
+        Some(reqwest::StatusCode::NOT_FOUND),
+    );
+}
@@ -0,0 +1,62 @@
+//! # Trace
+//!
+//! `client::trace` consists of functions for recording structured fields on the current `tracing` span of a client operation. These are no-ops unless the `tracing` feature is enabled.
+use std::time::Instant;
+
+// query parameter names containing any of these are considered secrets and redacted from spans
+#[cfg(feature = "tracing")]
+const SECRETS: [&str; 5] = ["token", "secret", "password", "key", "auth"];
+
+// redact the values of secret query parameters
+#[cfg(feature = "tracing")]
+pub(crate) fn redact(params: Option<&str>) -> Option<String> {
+    params.map(|params| {
+        params
+            .split('&')
+            .map(|param| match param.split_once('=') {
+                Some((name, _))
+                    if SECRETS
+                        .iter()
+                        .any(|secret| name.to_ascii_lowercase().contains(secret)) =>
+                {
+                    format!("{name}=[redacted]")
+                }
+                _ => String::from(param),
+            })
+            .collect::<Vec<String>>()
+            .join("&")
+    })
+}
+
+// record the response status and the latency since the request started
+pub(crate) fn record_response(status: reqwest::StatusCode, start: Instant) {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("status", status.as_u16());
+        span.record("latency_ms", start.elapsed().as_millis() as u64);
+    }
+    #[cfg(not(feature = "tracing"))]
+    let _ = (status, start);
+}
+
+// record that the response was served from the cache without a request
+pub(crate) fn record_cached() {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("cached", true);
+}
+
+// record the size of a page of results and whether more results exist
+pub(crate) fn record_page(page_size: usize, has_more: bool) {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("page_size", page_size);
+        span.record("has_more", has_more);
+    }
+    #[cfg(not(feature = "tracing"))]
+    let _ = (page_size, has_more);
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests;
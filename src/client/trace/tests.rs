@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn test_redact() {
+    assert_eq!(redact(None), None);
+    assert_eq!(
+        redact(Some("limit=100&nameContains=Portal")).as_deref(),
+        Some("limit=100&nameContains=Portal"),
+    );
+    assert_eq!(
+        redact(Some("limit=100&apiKey=abc&accessToken=def&flag")).as_deref(),
+        Some("limit=100&apiKey=[redacted]&accessToken=[redacted]&flag"),
+    );
+}
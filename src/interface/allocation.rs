@@ -3,74 +3,199 @@
 //! `interface::allocation` consists of functions for interfacing with the Ruddr Allocation endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::allocation;
-use crate::model::types;
+use crate::client::{client, error, resource};
+use crate::model::{allocation, member, project, role, types};
 
 /// Retrieves a specific Ruddr Allocation object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/allocations/get-an-allocation.md)
 /// ```ignore
-/// let allocation = allocation(&client, types::UUID::try_from("212b8272-ed2a-4a91-950a-8a06b3546144").expect("uuid conversion failed")).await?;
+/// let allocation = allocation(&client, types::Id::try_from("212b8272-ed2a-4a91-950a-8a06b3546144").expect("uuid conversion failed")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn allocation(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<allocation::Allocation, reqwest::Error> {
+    id: types::Id<allocation::Allocation>,
+) -> Result<allocation::Allocation, error::Error> {
     // retrieve allocation
-    Ok(client
-        .read::<allocation::Allocation>(&format!("allocations/{id}"), None)
-        .await?)
+    client.get::<allocation::Allocation>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Allocation objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Allocation objects. Unspecified filters are omitted from the request.
+/// The date filters each bound one end of the allocation against one date, and so an allocation overlapping a date range is best queried through the `overlapping` constructor.
+/// ```ignore
+/// let query = AllocationsQuery {
+///     project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")),
+///     booking_type: Some(allocation::BookingType::Confirmed),
+///     start_on_after: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct AllocationsQuery {
+    pub assignment_type: Option<allocation::AssignmentType>,
+    pub resource_type: Option<allocation::ResourceType>,
+    pub booking_type: Option<allocation::BookingType>,
+    pub member: Option<types::Id<member::Member>>,
+    pub placeholder: Option<types::UUID>,
+    pub project: Option<types::Id<project::Project>>,
+    pub role: Option<types::Id<role::Role>>,
+    pub is_billable: Option<bool>,
+    pub start_on_after: Option<types::Date>,
+    pub start_on_before: Option<types::Date>,
+    pub end_on_after: Option<types::Date>,
+    pub end_on_before: Option<types::Date>,
+    pub starting_after: Option<types::Id<allocation::Allocation>>,
+    pub ending_before: Option<types::Id<allocation::Allocation>>,
+}
+
+impl AllocationsQuery {
+    /// Query for allocations overlapping the inclusive date range i.e. starting on or before the range end and ending on or after the range start.
+    /// ```ignore
+    /// let query = AllocationsQuery::overlapping(
+    ///     types::Date::try_from("2024-01-01").expect("date conversion failed"),
+    ///     types::Date::try_from("2024-01-31").expect("date conversion failed"),
+    /// );
+    /// ```
+    pub fn overlapping(start: types::Date, end: types::Date) -> Self {
+        Self {
+            start_on_before: Some(end),
+            end_on_after: Some(start),
+            ..Default::default()
+        }
+    }
+
+    /// Query for allocations contained within the inclusive date range i.e. starting on or after the range start and ending on or before the range end.
+    /// ```ignore
+    /// let query = AllocationsQuery::within(
+    ///     types::Date::try_from("2024-01-01").expect("date conversion failed"),
+    ///     types::Date::try_from("2024-01-31").expect("date conversion failed"),
+    /// );
+    /// ```
+    pub fn within(start: types::Date, end: types::Date) -> Self {
+        Self {
+            start_on_after: Some(start),
+            end_on_before: Some(end),
+            ..Default::default()
+        }
+    }
+}
+
+impl resource::Query for AllocationsQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(assignment_type) = &self.assignment_type {
+            write!(params, "&assignmentTypeId={}", assignment_type).unwrap();
+        }
+        if let Some(resource_type) = &self.resource_type {
+            write!(params, "&resourceTypeId={}", resource_type).unwrap();
+        }
+        if let Some(booking_type) = &self.booking_type {
+            write!(params, "&bookingTypeId={}", booking_type).unwrap();
+        }
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(placeholder) = &self.placeholder {
+            write!(params, "&placeholderId={}", placeholder).unwrap();
+        }
+        if let Some(project) = &self.project {
+            write!(params, "&projectId={}", project).unwrap();
+        }
+        if let Some(role) = &self.role {
+            write!(params, "&roleId={}", role).unwrap();
+        }
+        if let Some(is_billable) = self.is_billable {
+            write!(params, "&isBillable={}", is_billable).unwrap();
+        }
+        if let Some(start_on_after) = &self.start_on_after {
+            write!(params, "&startOnAfter={}", start_on_after).unwrap();
+        }
+        if let Some(start_on_before) = &self.start_on_before {
+            write!(params, "&startOnBefore={}", start_on_before).unwrap();
+        }
+        if let Some(end_on_after) = &self.end_on_after {
+            write!(params, "&endOnAfter={}", end_on_after).unwrap();
+        }
+        if let Some(end_on_before) = &self.end_on_before {
+            write!(params, "&endOnBefore={}", end_on_before).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Allocation objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/allocations/list-allocations.md)
 /// ```ignore
 /// let allocations = allocations(
 ///     &client,
-///     Some(allocation::AssignmentType::Project),
-///     Some(types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
-///     Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-///     Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-///     None,
-///     None,
+///     &AllocationsQuery {
+///         assignment_type: Some(allocation::AssignmentType::Project),
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///         ..AllocationsQuery::overlapping(
+///             types::Date::try_from("2024-01-01").expect("date conversion failed"),
+///             types::Date::try_from("2024-01-31").expect("date conversion failed"),
+///         )
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn allocations(
     client: &client::Client,
-    assignment_type: Option<allocation::AssignmentType>,
-    member: Option<types::UUID>,
-    start_date: Option<types::Date>,
-    end_date: Option<types::Date>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<allocation::Allocations, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(assignment_type) = assignment_type {
-        write!(params, "&assignmentTypeId={}", assignment_type).unwrap();
-    }
-    if let Some(member) = member {
-        write!(params, "&memberId={}", member).unwrap();
-    }
-    if let Some(start_date) = start_date {
-        write!(params, "&startOnBefore={}", start_date).unwrap();
-    }
-    if let Some(end_date) = end_date {
-        write!(params, "&endOnAfter={}", end_date).unwrap();
+    query: &AllocationsQuery,
+) -> Result<allocation::Allocations, error::Error> {
+    // retrieve allocations
+    client.list::<allocation::Allocation>(query).await
+}
+
+/// Retrieves specific Ruddr Allocation objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = allocations_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn allocations_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<allocation::Allocation>>,
+    concurrency: usize,
+) -> resource::Batch<allocation::Allocation> {
+    // retrieve allocations
+    client
+        .get_many::<allocation::Allocation>(ids, concurrency)
+        .await
+}
+
+impl resource::Resource for allocation::Allocation {
+    const ENDPOINT: &'static str = "allocations";
+    type List = allocation::Allocations;
+    type Query<'query> = AllocationsQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<allocation::Allocation> for allocation::Allocations {
+    fn results(&self) -> &[allocation::Allocation] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<allocation::Allocation> {
+        self.results
     }
 
-    // retrieve allocations
-    Ok(client
-        .read::<allocation::Allocations>("allocations", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
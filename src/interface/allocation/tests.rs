@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_allocation() {
@@ -7,7 +8,7 @@ async fn test_allocation() {
     assert_eq!(
         allocation(
             &client,
-            types::UUID::try_from("212b8272-ed2a-4a91-950a-8a06b3546144")
+            types::Id::try_from("212b8272-ed2a-4a91-950a-8a06b3546144")
                 .expect("uuid conversion failed")
         )
         .await
@@ -17,6 +18,49 @@ async fn test_allocation() {
     )
 }
 
+#[test]
+fn test_allocations_query_overlapping() {
+    assert_eq!(
+        AllocationsQuery::overlapping(
+            types::Date::try_from("2024-01-01").expect("date conversion failed"),
+            types::Date::try_from("2024-01-31").expect("date conversion failed"),
+        )
+        .params(),
+        "limit=100&startOnBefore=2024-01-31&endOnAfter=2024-01-01",
+    )
+}
+
+#[test]
+fn test_allocations_query_within() {
+    assert_eq!(
+        AllocationsQuery::within(
+            types::Date::try_from("2024-01-01").expect("date conversion failed"),
+            types::Date::try_from("2024-01-31").expect("date conversion failed"),
+        )
+        .params(),
+        "limit=100&startOnAfter=2024-01-01&endOnBefore=2024-01-31",
+    )
+}
+
+#[test]
+fn test_allocations_query_params() {
+    assert_eq!(AllocationsQuery::default().params(), "limit=100");
+    assert_eq!(
+        AllocationsQuery {
+            resource_type: Some(allocation::ResourceType::Placeholder),
+            booking_type: Some(allocation::BookingType::Soft),
+            placeholder: Some(
+                types::UUID::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86")
+                    .expect("invalid UUID")
+            ),
+            is_billable: Some(true),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&resourceTypeId=placeholder&bookingTypeId=soft&placeholderId=3f3df320-dd95-4a42-8eae-99243fb2ea86&isBillable=true",
+    )
+}
+
 #[tokio::test]
 async fn test_allocations() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -24,15 +68,17 @@ async fn test_allocations() {
     assert_eq!(
         allocations(
             &client,
-            Some(allocation::AssignmentType::Project),
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("invalid UUID")
-            ),
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            None,
+            &AllocationsQuery {
+                assignment_type: Some(allocation::AssignmentType::Project),
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("invalid UUID")
+                ),
+                ..AllocationsQuery::overlapping(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                )
+            },
         )
         .await
         .expect_err("allocations retrieval did not fail on auth")
@@ -3,55 +3,114 @@
 //! `interface::cost` consists of functions for interfacing with the Ruddr Cost period endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{cost, types};
+use crate::client::{client, error, resource};
+use crate::model::{cost, member, types};
 
 /// Retrieves a specific Ruddr Cost period object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/cost-periods/get-a-cost-period.md)
 /// ```ignore
-/// let cost = cost(&client, types::UUID::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c").expect("invalid UUID")).await?;
+/// let cost = cost(&client, types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c").expect("invalid UUID")).await?;
 /// ```
-pub async fn cost(client: &client::Client, id: types::UUID) -> Result<cost::Cost, reqwest::Error> {
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn cost(
+    client: &client::Client,
+    id: types::Id<cost::Cost>,
+) -> Result<cost::Cost, error::Error> {
     // retrieve cost target period
-    Ok(client
-        .read::<cost::Cost>(&format!("cost-periods/{id}"), None)
-        .await?)
+    client.get::<cost::Cost>(id).await
+}
+
+/// Query filters for List operations on Ruddr Cost period objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = CostsQuery {
+///     member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct CostsQuery {
+    pub member: Option<types::Id<member::Member>>,
+    pub starting_after: Option<types::Id<cost::Cost>>,
+    pub ending_before: Option<types::Id<cost::Cost>>,
+}
+
+impl resource::Query for CostsQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
 }
 
-/// Retrieves the first 100 Ruddr Cost period objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Retrieves the first 100 Ruddr Cost period objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/cost-periods/list-cost-periods.md)
 /// ```ignore
 /// let costs = costs(
 ///     &client,
-///     Some(types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
-///     None,
-///     None,
+///     &CostsQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn costs(
     client: &client::Client,
-    member: Option<types::UUID>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<cost::Costs, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(member) = member {
-        write!(params, "&memberId={}", member).unwrap();
+    query: &CostsQuery,
+) -> Result<cost::Costs, error::Error> {
+    // retrieve cost target periods
+    client.list::<cost::Cost>(query).await
+}
+
+/// Retrieves specific Ruddr Cost period objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = costs_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn costs_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<cost::Cost>>,
+    concurrency: usize,
+) -> resource::Batch<cost::Cost> {
+    // retrieve cost periods
+    client.get_many::<cost::Cost>(ids, concurrency).await
+}
+
+impl resource::Resource for cost::Cost {
+    const ENDPOINT: &'static str = "cost-periods";
+    type List = cost::Costs;
+    type Query<'query> = CostsQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<cost::Cost> for cost::Costs {
+    fn results(&self) -> &[cost::Cost] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<cost::Cost> {
+        self.results
     }
 
-    // retrieve cost target periods
-    Ok(client
-        .read::<cost::Costs>("cost-periods", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_cost() {
@@ -7,7 +8,7 @@ async fn test_cost() {
     assert_eq!(
         cost(
             &client,
-            types::UUID::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
+            types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
                 .expect("uuid conversion failed"),
         )
         .await
@@ -17,6 +18,26 @@ async fn test_cost() {
     )
 }
 
+#[test]
+fn test_costs_query_params() {
+    assert_eq!(CostsQuery::default().params(), "limit=100");
+    assert_eq!(
+        CostsQuery {
+            member: Some(
+                types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                    .expect("uuid conversion failed")
+            ),
+            ending_before: Some(
+                types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
+                    .expect("uuid conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&memberId=ec5543de-3b0f-47a0-b8ef-a6e18dc4b885&endingBefore=b3a100b0-8e71-4f39-9d96-32f11838aa8c",
+    )
+}
+
 #[tokio::test]
 async fn test_costs() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -24,12 +45,13 @@ async fn test_costs() {
     assert_eq!(
         costs(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("invalid UUID")
-            ),
-            None,
-            None,
+            &CostsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("invalid UUID")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("costs retrieval did not fail on auth")
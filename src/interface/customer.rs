@@ -3,58 +3,201 @@
 //! `interface::customer` consists of functions for interfacing with the Ruddr Client endpoints. This module and base Read function are named differently from the endpoint so as to avoid naming collisions with the API client module in external usage. All other code associated with this endpoint utilizes the endpoint name `client`.
 use std::fmt::Write;
 
-use crate::client::client;
+use crate::client::{client, error, resource};
 use crate::model;
 
 /// Retrieves a specific Ruddr Client object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/clients/get-a-client.md)
 /// ```ignore
-/// let customer = customer(&client, types::UUID::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0").expect("invalid UUID")).await?;
+/// let customer = customer(&client, types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn customer(
     client: &client::Client,
-    id: model::types::UUID,
-) -> Result<model::client::Client, reqwest::Error> {
+    id: model::types::Id<model::client::Client>,
+) -> Result<model::client::Client, error::Error> {
     // retrieve client
-    Ok(client
-        .read::<model::client::Client>(&format!("clients/{id}"), None)
-        .await?)
+    client.get::<model::client::Client>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Client objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Client objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = ClientsQuery {
+///     record_status: Some(model::shared::RecordStatus::Active),
+///     is_internal: Some(false),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ClientsQuery<'query> {
+    pub code: Option<&'query str>,
+    pub name_contains: Option<&'query str>,
+    pub record_status: Option<model::shared::RecordStatus>,
+    pub owner: Option<model::types::Id<model::member::Member>>,
+    pub practice: Option<model::types::UUID>,
+    pub business_unit: Option<model::types::UUID>,
+    pub tag: Option<model::types::UUID>,
+    pub is_internal: Option<bool>,
+    pub starting_after: Option<model::types::Id<model::client::Client>>,
+    pub ending_before: Option<model::types::Id<model::client::Client>>,
+}
+
+impl resource::Query for ClientsQuery<'_> {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(code) = self.code {
+            write!(params, "&code={}", resource::encode(code)).unwrap();
+        }
+        if let Some(name_contains) = self.name_contains {
+            write!(params, "&nameContains={}", resource::encode(name_contains)).unwrap();
+        }
+        if let Some(record_status) = &self.record_status {
+            write!(params, "&recordStatusId={}", record_status).unwrap();
+        }
+        if let Some(owner) = &self.owner {
+            write!(params, "&ownerId={}", owner).unwrap();
+        }
+        if let Some(practice) = &self.practice {
+            write!(params, "&practiceId={}", practice).unwrap();
+        }
+        if let Some(business_unit) = &self.business_unit {
+            write!(params, "&businessUnitId={}", business_unit).unwrap();
+        }
+        if let Some(tag) = &self.tag {
+            write!(params, "&tagId={}", tag).unwrap();
+        }
+        if let Some(is_internal) = self.is_internal {
+            write!(params, "&isInternal={}", is_internal).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Client objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/clients/list-clients.md)
 /// ```ignore
 /// let clients = clients(
 ///     &client,
-///     Some("JOE"),
-///     None,
-///     None,
+///     &ClientsQuery {
+///         code: Some("JOE"),
+///         record_status: Some(model::shared::RecordStatus::Active),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn clients(
     client: &client::Client,
-    code: Option<&str>,
-    starting_after: Option<model::types::UUID>,
-    ending_before: Option<model::types::UUID>,
-) -> Result<model::client::Clients, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(code) = code {
-        write!(params, "&code={}", code).unwrap();
+    query: &ClientsQuery<'_>,
+) -> Result<model::client::Clients, error::Error> {
+    // retrieve clients
+    client.list::<model::client::Client>(query).await
+}
+
+/// Creates a Ruddr Client object from the input model after client-side validation, and deserializes the created object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/create-a-client.md)
+/// ```ignore
+/// let customer = create_client(
+///     &client,
+///     &model::client::ClientInput {
+///         name: Some(String::from("Joe's Shop")),
+///         ..Default::default()
+///     },
+/// ).await?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn create_client(
+    client: &client::Client,
+    input: &model::client::ClientInput,
+) -> Result<model::client::Client, error::Error> {
+    // validate input
+    input.validate_create()?;
+
+    // create client
+    client
+        .create::<model::client::ClientInput, model::client::Client>("clients", input)
+        .await
+}
+
+/// Updates a specific Ruddr Client object by id from the input model after client-side validation, and deserializes the updated object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/clients/update-a-client.md)
+/// ```ignore
+/// let customer = update_client(
+///     &client,
+///     types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0").expect("invalid UUID"),
+///     &model::client::ClientInput {
+///         record_status_id: Some(model::shared::RecordStatus::Archived),
+///         ..Default::default()
+///     },
+/// ).await?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn update_client(
+    client: &client::Client,
+    id: model::types::Id<model::client::Client>,
+    input: &model::client::ClientInput,
+) -> Result<model::client::Client, error::Error> {
+    // validate input
+    input.validate()?;
+
+    // update client
+    client
+        .update::<model::client::ClientInput, model::client::Client>(
+            &format!("clients/{id}"),
+            input,
+        )
+        .await
+}
+
+/// Retrieves specific Ruddr Client objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = clients_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn clients_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = model::types::Id<model::client::Client>>,
+    concurrency: usize,
+) -> resource::Batch<model::client::Client> {
+    // retrieve clients
+    client
+        .get_many::<model::client::Client>(ids, concurrency)
+        .await
+}
+
+impl resource::Resource for model::client::Client {
+    const ENDPOINT: &'static str = "clients";
+    type List = model::client::Clients;
+    type Query<'query> = ClientsQuery<'query>;
+
+    fn id(&self) -> &model::types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<model::client::Client> for model::client::Clients {
+    fn results(&self) -> &[model::client::Client] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<model::client::Client> {
+        self.results
     }
 
-    // retrieve clients
-    Ok(client
-        .read::<model::client::Clients>("clients", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
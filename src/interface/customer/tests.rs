@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_customer() {
@@ -7,7 +8,7 @@ async fn test_customer() {
     assert_eq!(
         customer(
             &client,
-            model::types::UUID::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+            model::types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
                 .expect("uuid conversion failed")
         )
         .await
@@ -17,15 +18,87 @@ async fn test_customer() {
     )
 }
 
+#[test]
+fn test_clients_query_params() {
+    assert_eq!(ClientsQuery::default().params(), "limit=100");
+    assert_eq!(
+        ClientsQuery {
+            name_contains: Some("Joe's Shop"),
+            record_status: Some(model::shared::RecordStatus::Active),
+            owner: Some(
+                model::types::Id::try_from("a6816355-8945-40aa-b798-b0d6fd89e437")
+                    .expect("uuid conversion failed")
+            ),
+            is_internal: Some(false),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&nameContains=Joe%27s+Shop&recordStatusId=active&ownerId=a6816355-8945-40aa-b798-b0d6fd89e437&isInternal=false",
+    )
+}
+
 #[tokio::test]
 async fn test_clients() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        clients(&client, Some("JOE"), None, None)
+        clients(
+            &client,
+            &ClientsQuery {
+                code: Some("JOE"),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("clients retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[tokio::test]
+async fn test_create_client() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        create_client(
+            &client,
+            &model::client::ClientInput {
+                name: Some(String::from("Joe's Shop")),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("client creation did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    );
+    assert_eq!(
+        create_client(&client, &model::client::ClientInput::default())
             .await
-            .expect_err("clients retrieval did not fail on auth")
-            .status(),
+            .expect_err("client creation did not fail on validation")
+            .to_string(),
+        "name is required to create a client",
+    )
+}
+
+#[tokio::test]
+async fn test_update_client() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        update_client(
+            &client,
+            model::types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+                .expect("uuid conversion failed"),
+            &model::client::ClientInput {
+                record_status_id: Some(model::shared::RecordStatus::Archived),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("client update did not fail on auth")
+        .status(),
         Some(reqwest::StatusCode::UNAUTHORIZED),
     )
 }
@@ -3,53 +3,156 @@
 //! `interface::expense_item` consists of functions for interfacing with the Ruddr Expense Item endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{expense_item, types};
+use crate::client::{client, error, resource};
+use crate::model::{expense_item, expense_report, member, project, shared, types};
 
 /// Retrieves a specific Ruddr Expense Item object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/expense-items/get-an-expense-item.md)
 /// ```ignore
-/// let expense_item = expense_item(&client, types::UUID::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522").expect("invalid UUID")).await?;
+/// let expense_item = expense_item(&client, types::Id::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn expense_item(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<expense_item::ExpenseItem, reqwest::Error> {
+    id: types::Id<expense_item::ExpenseItem>,
+) -> Result<expense_item::ExpenseItem, error::Error> {
     // retrieve expense item
-    Ok(client
-        .read::<expense_item::ExpenseItem>(&format!("expense-items/{id}"), None)
-        .await?)
+    client.get::<expense_item::ExpenseItem>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Expense Item objects, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Expense Item objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = ExpenseItemsQuery {
+///     member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///     status: Some(shared::Status::Approved),
+///     is_reimbursable: Some(true),
+///     date_on_after: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpenseItemsQuery {
+    pub expense_report: Option<types::Id<expense_report::ExpenseReport>>,
+    pub member: Option<types::Id<member::Member>>,
+    pub project: Option<types::Id<project::Project>>,
+    pub category: Option<types::UUID>,
+    pub status: Option<shared::Status>,
+    pub is_billable: Option<bool>,
+    pub is_reimbursable: Option<bool>,
+    pub invoiced: Option<bool>,
+    pub date_on_after: Option<types::Date>,
+    pub date_on_before: Option<types::Date>,
+    pub starting_after: Option<types::Id<expense_item::ExpenseItem>>,
+    pub ending_before: Option<types::Id<expense_item::ExpenseItem>>,
+}
+
+impl resource::Query for ExpenseItemsQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(expense_report) = &self.expense_report {
+            write!(params, "&expenseReportId={}", expense_report).unwrap();
+        }
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(project) = &self.project {
+            write!(params, "&projectId={}", project).unwrap();
+        }
+        if let Some(category) = &self.category {
+            write!(params, "&expenseCategoryId={}", category).unwrap();
+        }
+        if let Some(status) = &self.status {
+            write!(params, "&statusId={}", status).unwrap();
+        }
+        if let Some(is_billable) = self.is_billable {
+            write!(params, "&isBillable={}", is_billable).unwrap();
+        }
+        if let Some(is_reimbursable) = self.is_reimbursable {
+            write!(params, "&isReimbursable={}", is_reimbursable).unwrap();
+        }
+        if let Some(invoiced) = self.invoiced {
+            write!(params, "&invoiced={}", invoiced).unwrap();
+        }
+        if let Some(date_on_after) = &self.date_on_after {
+            write!(params, "&dateOnAfter={}", date_on_after).unwrap();
+        }
+        if let Some(date_on_before) = &self.date_on_before {
+            write!(params, "&dateOnBefore={}", date_on_before).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Expense Item objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/expense-items/list-expense-items.md)
 /// ```ignore
-/// let expense_items = expense_items(&client, Some(types::UUID::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID")), None, None).await?;
+/// let expense_items = expense_items(
+///     &client,
+///     &ExpenseItemsQuery {
+///         expense_report: Some(types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID")),
+///         invoiced: Some(false),
+///         ..Default::default()
+///     },
+/// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn expense_items(
     client: &client::Client,
-    expense_report: Option<types::UUID>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<expense_item::ExpenseItems, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(expense_report) = expense_report {
-        write!(params, "&expenseReportId={}", expense_report).unwrap();
+    query: &ExpenseItemsQuery,
+) -> Result<expense_item::ExpenseItems, error::Error> {
+    // retrieve expense items
+    client.list::<expense_item::ExpenseItem>(query).await
+}
+
+/// Retrieves specific Ruddr Expense item objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = expense_items_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn expense_items_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<expense_item::ExpenseItem>>,
+    concurrency: usize,
+) -> resource::Batch<expense_item::ExpenseItem> {
+    // retrieve expense items
+    client
+        .get_many::<expense_item::ExpenseItem>(ids, concurrency)
+        .await
+}
+
+impl resource::Resource for expense_item::ExpenseItem {
+    const ENDPOINT: &'static str = "expense-items";
+    type List = expense_item::ExpenseItems;
+    type Query<'query> = ExpenseItemsQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<expense_item::ExpenseItem> for expense_item::ExpenseItems {
+    fn results(&self) -> &[expense_item::ExpenseItem] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<expense_item::ExpenseItem> {
+        self.results
     }
 
-    // retrieve expense items
-    Ok(client
-        .read::<expense_item::ExpenseItems>("expense-items", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
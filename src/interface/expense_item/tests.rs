@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_expense_item() {
@@ -7,7 +8,7 @@ async fn test_expense_item() {
     assert_eq!(
         expense_item(
             &client,
-            types::UUID::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522")
+            types::Id::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522")
                 .expect("uuid conversion failed")
         )
         .await
@@ -17,6 +18,27 @@ async fn test_expense_item() {
     )
 }
 
+#[test]
+fn test_expense_items_query_params() {
+    assert_eq!(ExpenseItemsQuery::default().params(), "limit=100");
+    assert_eq!(
+        ExpenseItemsQuery {
+            member: Some(
+                types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")
+            ),
+            status: Some(shared::Status::Approved),
+            is_reimbursable: Some(true),
+            invoiced: Some(false),
+            date_on_after: Some(
+                types::Date::try_from("2024-01-01").expect("date conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&memberId=ec5543de-3b0f-47a0-b8ef-a6e18dc4b885&statusId=approved&isReimbursable=true&invoiced=false&dateOnAfter=2024-01-01",
+    )
+}
+
 #[tokio::test]
 async fn test_expense_items() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -24,12 +46,13 @@ async fn test_expense_items() {
     assert_eq!(
         expense_items(
             &client,
-            Some(
-                types::UUID::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
-                    .expect("invalid UUID")
-            ),
-            None,
-            None,
+            &ExpenseItemsQuery {
+                expense_report: Some(
+                    types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+                        .expect("invalid UUID")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("expense_items retrieval did not fail on auth")
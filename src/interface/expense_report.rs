@@ -3,48 +3,130 @@
 //! `interface::expense_report` consists of functions for interfacing with the Ruddr Expense Report endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{expense_report, types};
+use crate::client::{client, error, resource};
+use crate::model::{expense_report, member, shared, types};
 
 /// Retrieves a specific Ruddr Expense Report object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/expense-reports/get-an-expense-report.md)
 /// ```ignore
-/// let expense_report = expense_report(&client, types::UUID::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID")).await?;
+/// let expense_report = expense_report(&client, types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn expense_report(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<expense_report::ExpenseReport, reqwest::Error> {
+    id: types::Id<expense_report::ExpenseReport>,
+) -> Result<expense_report::ExpenseReport, error::Error> {
     // retrieve expense report
-    Ok(client
-        .read::<expense_report::ExpenseReport>(&format!("expense-reports/{id}"), None)
-        .await?)
+    client.get::<expense_report::ExpenseReport>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Expense Report objects, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Expense Report objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = ExpenseReportsQuery {
+///     member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///     status: Some(shared::Status::PendingApproval),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ExpenseReportsQuery {
+    pub member: Option<types::Id<member::Member>>,
+    pub status: Option<shared::Status>,
+    pub date_on_after: Option<types::Date>,
+    pub date_on_before: Option<types::Date>,
+    pub starting_after: Option<types::Id<expense_report::ExpenseReport>>,
+    pub ending_before: Option<types::Id<expense_report::ExpenseReport>>,
+}
+
+impl resource::Query for ExpenseReportsQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(status) = &self.status {
+            write!(params, "&statusId={}", status).unwrap();
+        }
+        if let Some(date_on_after) = &self.date_on_after {
+            write!(params, "&dateOnAfter={}", date_on_after).unwrap();
+        }
+        if let Some(date_on_before) = &self.date_on_before {
+            write!(params, "&dateOnBefore={}", date_on_before).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Expense Report objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/expense-reports/list-expense-reports.md)
 /// ```ignore
-/// let expense_reports = expense_reports(&client, None, None).await?;
+/// let expense_reports = expense_reports(
+///     &client,
+///     &ExpenseReportsQuery {
+///         date_on_after: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///         date_on_before: Some(types::Date::try_from("2024-01-31").expect("date conversion failed")),
+///         ..Default::default()
+///     },
+/// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn expense_reports(
     client: &client::Client,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<expense_report::ExpenseReports, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+    query: &ExpenseReportsQuery,
+) -> Result<expense_report::ExpenseReports, error::Error> {
+    // retrieve expense reports
+    client.list::<expense_report::ExpenseReport>(query).await
+}
+
+/// Retrieves specific Ruddr Expense report objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = expense_reports_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn expense_reports_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<expense_report::ExpenseReport>>,
+    concurrency: usize,
+) -> resource::Batch<expense_report::ExpenseReport> {
+    // retrieve expense reports
+    client
+        .get_many::<expense_report::ExpenseReport>(ids, concurrency)
+        .await
+}
+
+impl resource::Resource for expense_report::ExpenseReport {
+    const ENDPOINT: &'static str = "expense-reports";
+    type List = expense_report::ExpenseReports;
+    type Query<'query> = ExpenseReportsQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+}
+
+impl resource::Page<expense_report::ExpenseReport> for expense_report::ExpenseReports {
+    fn results(&self) -> &[expense_report::ExpenseReport] {
+        &self.results
     }
 
-    // retrieve expense reports
-    Ok(client
-        .read::<expense_report::ExpenseReports>("expense-reports", Some(&params))
-        .await?)
+    fn into_results(self) -> Vec<expense_report::ExpenseReport> {
+        self.results
+    }
+
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_expense_report() {
@@ -7,7 +8,7 @@ async fn test_expense_report() {
     assert_eq!(
         expense_report(
             &client,
-            types::UUID::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+            types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
                 .expect("uuid conversion failed")
         )
         .await
@@ -17,12 +18,31 @@ async fn test_expense_report() {
     )
 }
 
+#[test]
+fn test_expense_reports_query_params() {
+    assert_eq!(ExpenseReportsQuery::default().params(), "limit=100");
+    assert_eq!(
+        ExpenseReportsQuery {
+            member: Some(
+                types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")
+            ),
+            status: Some(shared::Status::PendingApproval),
+            date_on_before: Some(
+                types::Date::try_from("2024-01-31").expect("date conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&memberId=ec5543de-3b0f-47a0-b8ef-a6e18dc4b885&statusId=pending_approval&dateOnBefore=2024-01-31",
+    )
+}
+
 #[tokio::test]
 async fn test_expense_reports() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        expense_reports(&client, None, None)
+        expense_reports(&client, &ExpenseReportsQuery::default())
             .await
             .expect_err("expense_reports retrieval did not fail on auth")
             .status(),
@@ -3,57 +3,159 @@
 //! `interface::member` consists of functions for interfacing with the Ruddr Member endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
+use crate::client::{client, error, resource};
 use crate::model::{member, types};
 
 /// Retrieves a specific Ruddr Workspace Member object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/members/get-a-member.md)
 /// ```ignore
-/// let member = member(&client, types::UUID::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86").expect("invalid UUID")).await?;
+/// let member = member(&client, types::Id::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn member(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<member::Member, reqwest::Error> {
+    id: types::Id<member::Member>,
+) -> Result<member::Member, error::Error> {
     // retrieve member
-    Ok(client
-        .read::<member::Member>(&format!("members/{id}"), None)
-        .await?)
+    client.get::<member::Member>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Workspace Member objects, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Workspace Member objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = MembersQuery {
+///     is_active: Some(true),
+///     is_billable: Some(true),
+///     employment_type: Some(member::EmploymentType::Employee),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct MembersQuery<'query> {
+    pub name_contains: Option<&'query str>,
+    pub email_contains: Option<&'query str>,
+    pub is_active: Option<bool>,
+    pub is_billable: Option<bool>,
+    pub employment_type: Option<member::EmploymentType>,
+    pub security_role: Option<types::UUID>,
+    pub practice: Option<types::UUID>,
+    pub location: Option<types::UUID>,
+    pub manager: Option<types::Id<member::Member>>,
+    pub job_title: Option<types::UUID>,
+    pub starting_after: Option<types::Id<member::Member>>,
+    pub ending_before: Option<types::Id<member::Member>>,
+}
+
+impl resource::Query for MembersQuery<'_> {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(name_contains) = self.name_contains {
+            write!(params, "&nameContains={}", resource::encode(name_contains)).unwrap();
+        }
+        if let Some(email_contains) = self.email_contains {
+            write!(
+                params,
+                "&emailContains={}",
+                resource::encode(email_contains)
+            )
+            .unwrap();
+        }
+        if let Some(is_active) = self.is_active {
+            write!(params, "&isActive={}", is_active).unwrap();
+        }
+        if let Some(is_billable) = self.is_billable {
+            write!(params, "&isBillable={}", is_billable).unwrap();
+        }
+        if let Some(employment_type) = &self.employment_type {
+            write!(params, "&employmentTypeId={}", employment_type).unwrap();
+        }
+        if let Some(security_role) = &self.security_role {
+            write!(params, "&securityRoleId={}", security_role).unwrap();
+        }
+        if let Some(practice) = &self.practice {
+            write!(params, "&practiceId={}", practice).unwrap();
+        }
+        if let Some(location) = &self.location {
+            write!(params, "&locationId={}", location).unwrap();
+        }
+        if let Some(manager) = &self.manager {
+            write!(params, "&managerId={}", manager).unwrap();
+        }
+        if let Some(job_title) = &self.job_title {
+            write!(params, "&jobTitleId={}", job_title).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Workspace Member objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/members/list-members.md)
 /// ```ignore
-/// let members = members(&client, Some("Joe"), Some("foo@bar.com"), None, None).await?;
+/// let members = members(
+///     &client,
+///     &MembersQuery {
+///         name_contains: Some("Joe"),
+///         email_contains: Some("foo@bar.com"),
+///         is_active: Some(true),
+///         ..Default::default()
+///     },
+/// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn members(
     client: &client::Client,
-    name_contains: Option<&str>,
-    email_contains: Option<&str>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<member::Members, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(name_contains) = name_contains {
-        write!(params, "&nameContains={}", name_contains).unwrap();
-    }
-    if let Some(email_contains) = email_contains {
-        write!(params, "&emailContains={}", email_contains).unwrap();
+    query: &MembersQuery<'_>,
+) -> Result<member::Members, error::Error> {
+    // retrieve members
+    client.list::<member::Member>(query).await
+}
+
+/// Retrieves specific Ruddr Member objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = members_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn members_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<member::Member>>,
+    concurrency: usize,
+) -> resource::Batch<member::Member> {
+    // retrieve members
+    client.get_many::<member::Member>(ids, concurrency).await
+}
+
+impl resource::Resource for member::Member {
+    const ENDPOINT: &'static str = "members";
+    type List = member::Members;
+    type Query<'query> = MembersQuery<'query>;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<member::Member> for member::Members {
+    fn results(&self) -> &[member::Member] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<member::Member> {
+        self.results
     }
 
-    // retrieve members
-    Ok(client
-        .read::<member::Members>("members", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
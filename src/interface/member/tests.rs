@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_member() {
@@ -7,7 +8,7 @@ async fn test_member() {
     assert_eq!(
         member(
             &client,
-            types::UUID::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86").expect("invalid UUID")
+            types::Id::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86").expect("invalid UUID")
         )
         .await
         .expect_err("member retrieval did not fail on auth")
@@ -16,15 +17,42 @@ async fn test_member() {
     )
 }
 
+#[test]
+fn test_members_query_params() {
+    assert_eq!(MembersQuery::default().params(), "limit=100");
+    assert_eq!(
+        MembersQuery {
+            is_active: Some(true),
+            is_billable: Some(true),
+            employment_type: Some(member::EmploymentType::Employee),
+            manager: Some(
+                types::Id::try_from("a6816355-8945-40aa-b798-b0d6fd89e437").expect("invalid UUID")
+            ),
+            name_contains: Some("Joe Smith"),
+            email_contains: Some("joe+ruddr@bar.com"),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&nameContains=Joe+Smith&emailContains=joe%2Bruddr%40bar.com&isActive=true&isBillable=true&employmentTypeId=employee&managerId=a6816355-8945-40aa-b798-b0d6fd89e437",
+    )
+}
+
 #[tokio::test]
 async fn test_members() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        members(&client, Some("Joe"), Some("foo@bar.com"), None, None)
-            .await
-            .expect_err("members retrieval did not fail on auth")
-            .status(),
+        members(
+            &client,
+            &MembersQuery {
+                name_contains: Some("Joe"),
+                email_contains: Some("foo@bar.com"),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("members retrieval did not fail on auth")
+        .status(),
         Some(reqwest::StatusCode::UNAUTHORIZED),
     )
 }
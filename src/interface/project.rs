@@ -3,73 +3,226 @@
 //! `interface::project` consists of functions for interfacing with the Ruddr Project endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{project, types};
+use crate::client::{client, error, resource};
+use crate::model::{self, member, project, shared, types};
 
 /// Retrieves a specific Ruddr Project object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/projects/get-a-project.md)
 /// ```ignore
-/// let project = project(&client, types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")).await?;
+/// let project = project(&client, types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn project(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<project::Project, reqwest::Error> {
+    id: types::Id<project::Project>,
+) -> Result<project::Project, error::Error> {
     // retrieve project
-    Ok(client
-        .read::<project::Project>(&format!("projects/{id}"), None)
-        .await?)
+    client.get::<project::Project>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Project objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Project objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = ProjectsQuery {
+///     record_status: Some(shared::RecordStatus::Active),
+///     billing_type: Some(project::BillingType::Fixed),
+///     start_on_after: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct ProjectsQuery<'query> {
+    pub client_id: Option<types::Id<model::client::Client>>,
+    pub project_type: Option<types::UUID>,
+    pub status: Option<project::Status>,
+    pub record_status: Option<shared::RecordStatus>,
+    pub billing_type: Option<project::BillingType>,
+    pub practice: Option<types::UUID>,
+    pub business_unit: Option<types::UUID>,
+    pub tag: Option<types::UUID>,
+    pub sales_representative: Option<types::Id<member::Member>>,
+    pub name_contains: Option<&'query str>,
+    pub start_on_after: Option<types::Date>,
+    pub start_on_before: Option<types::Date>,
+    pub end_on_after: Option<types::Date>,
+    pub end_on_before: Option<types::Date>,
+    pub starting_after: Option<types::Id<project::Project>>,
+    pub ending_before: Option<types::Id<project::Project>>,
+}
+
+impl resource::Query for ProjectsQuery<'_> {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(client_id) = &self.client_id {
+            write!(params, "&clientId={}", client_id).unwrap();
+        }
+        if let Some(project_type) = &self.project_type {
+            write!(params, "&projectTypeId={}", project_type).unwrap();
+        }
+        if let Some(status) = &self.status {
+            write!(params, "&statusId={}", status).unwrap();
+        }
+        if let Some(record_status) = &self.record_status {
+            write!(params, "&recordStatusId={}", record_status).unwrap();
+        }
+        if let Some(billing_type) = &self.billing_type {
+            write!(params, "&billingTypeId={}", billing_type).unwrap();
+        }
+        if let Some(practice) = &self.practice {
+            write!(params, "&practiceId={}", practice).unwrap();
+        }
+        if let Some(business_unit) = &self.business_unit {
+            write!(params, "&businessUnitId={}", business_unit).unwrap();
+        }
+        if let Some(tag) = &self.tag {
+            write!(params, "&tagId={}", tag).unwrap();
+        }
+        if let Some(sales_representative) = &self.sales_representative {
+            write!(params, "&salesRepresentativeId={}", sales_representative).unwrap();
+        }
+        if let Some(name_contains) = self.name_contains {
+            write!(params, "&nameContains={}", resource::encode(name_contains)).unwrap();
+        }
+        if let Some(start_on_after) = &self.start_on_after {
+            write!(params, "&startOnAfter={}", start_on_after).unwrap();
+        }
+        if let Some(start_on_before) = &self.start_on_before {
+            write!(params, "&startOnBefore={}", start_on_before).unwrap();
+        }
+        if let Some(end_on_after) = &self.end_on_after {
+            write!(params, "&endOnAfter={}", end_on_after).unwrap();
+        }
+        if let Some(end_on_before) = &self.end_on_before {
+            write!(params, "&endOnBefore={}", end_on_before).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Project objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/projects/list-projects.md)
 /// ```ignore
 /// let projects = projects(
 ///     &client,
-///     Some(types::UUID::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
-///     Some(types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de").expect("invalid UUID")),
-///     Some(project::Status::InProgress),
-///     Some("my_project"),
-///     None,
-///     None,
+///     &ProjectsQuery {
+///         client_id: Some(types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
+///         status: Some(project::Status::InProgress),
+///         record_status: Some(shared::RecordStatus::Active),
+///         name_contains: Some("my_project"),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn projects(
     client: &client::Client,
-    client_id: Option<types::UUID>,
-    project_type: Option<types::UUID>,
-    status: Option<project::Status>,
-    name_contains: Option<&str>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<project::Projects, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(client_id) = client_id {
-        write!(params, "&clientId={}", client_id).unwrap();
-    }
-    if let Some(project_type) = project_type {
-        write!(params, "&projectTypeId={}", project_type).unwrap();
-    }
-    if let Some(status) = status {
-        write!(params, "&statusId={}", status).unwrap();
-    }
-    if let Some(name_contains) = name_contains {
-        write!(params, "&nameContains={}", name_contains).unwrap();
+    query: &ProjectsQuery<'_>,
+) -> Result<project::Projects, error::Error> {
+    // retrieve projects
+    client.list::<project::Project>(query).await
+}
+
+/// Creates a Ruddr Project object from the input model after client-side validation, and deserializes the created object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/create-a-project.md)
+/// ```ignore
+/// let project = create_project(
+///     &client,
+///     &project::ProjectInput {
+///         name: Some(String::from("Vendor Portal")),
+///         client_id: Some(types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
+///         billing_type_id: Some(project::BillingType::Fixed),
+///         fixed_fee: Some(shared::Decimal::new(50000, 0)),
+///         ..Default::default()
+///     },
+/// ).await?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn create_project(
+    client: &client::Client,
+    input: &project::ProjectInput,
+) -> Result<project::Project, error::Error> {
+    // validate input
+    input.validate_create()?;
+
+    // create project
+    client
+        .create::<project::ProjectInput, project::Project>("projects", input)
+        .await
+}
+
+/// Updates a specific Ruddr Project object by id from the input model after client-side validation, and deserializes the updated object to the corresponding model struct.
+/// [API Documentation](https://docs.ruddr.io/api-reference/projects/update-a-project.md)
+/// ```ignore
+/// let project = update_project(
+///     &client,
+///     types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"),
+///     &project::ProjectInput {
+///         status_id: Some(project::Status::Completed),
+///         ..Default::default()
+///     },
+/// ).await?;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn update_project(
+    client: &client::Client,
+    id: types::Id<project::Project>,
+    input: &project::ProjectInput,
+) -> Result<project::Project, error::Error> {
+    // validate input
+    input.validate()?;
+
+    // update project
+    client
+        .update::<project::ProjectInput, project::Project>(&format!("projects/{id}"), input)
+        .await
+}
+
+/// Retrieves specific Ruddr Project objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = projects_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn projects_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<project::Project>>,
+    concurrency: usize,
+) -> resource::Batch<project::Project> {
+    // retrieve projects
+    client.get_many::<project::Project>(ids, concurrency).await
+}
+
+impl resource::Resource for project::Project {
+    const ENDPOINT: &'static str = "projects";
+    type List = project::Projects;
+    type Query<'query> = ProjectsQuery<'query>;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<project::Project> for project::Projects {
+    fn results(&self) -> &[project::Project] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<project::Project> {
+        self.results
     }
 
-    // retrieve projects
-    Ok(client
-        .read::<project::Projects>("projects", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
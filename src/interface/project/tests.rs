@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::{Page, Query, Resource};
 
 #[tokio::test]
 async fn test_project() {
@@ -7,7 +8,7 @@ async fn test_project() {
     assert_eq!(
         project(
             &client,
-            types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")
+            types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")
         )
         .await
         .expect_err("project retrieval did not fail on auth")
@@ -16,6 +17,44 @@ async fn test_project() {
     )
 }
 
+#[test]
+fn test_projects_query_params() {
+    assert_eq!(ProjectsQuery::default().params(), "limit=100");
+    assert_eq!(
+        ProjectsQuery {
+            status: Some(project::Status::InProgress),
+            record_status: Some(shared::RecordStatus::Active),
+            billing_type: Some(project::BillingType::TimeAndMaterials),
+            tag: Some(
+                types::UUID::try_from("626db436-98bf-40cb-9937-c382af5d818c")
+                    .expect("invalid UUID")
+            ),
+            name_contains: Some("portal & api"),
+            start_on_after: Some(
+                types::Date::try_from("2024-01-01").expect("date conversion failed")
+            ),
+            end_on_before: Some(
+                types::Date::try_from("2024-12-31").expect("date conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&statusId=in_progress&recordStatusId=active&billingTypeId=tm&tagId=626db436-98bf-40cb-9937-c382af5d818c&nameContains=portal+%26+api&startOnAfter=2024-01-01&endOnBefore=2024-12-31",
+    )
+}
+
+#[test]
+fn test_project_resource() {
+    assert_eq!(project::Project::ENDPOINT, "projects");
+    let projects = project::Projects {
+        results: Vec::new(),
+        has_more: true,
+    };
+    assert!(projects.results().is_empty());
+    assert!(projects.has_more());
+    assert!(projects.into_results().is_empty());
+}
+
 #[tokio::test]
 async fn test_projects() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -23,18 +62,19 @@ async fn test_projects() {
     assert_eq!(
         projects(
             &client,
-            Some(
-                types::UUID::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
-                    .expect("invalid UUID")
-            ),
-            Some(
-                types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de")
-                    .expect("invalid UUID")
-            ),
-            Some(project::Status::InProgress),
-            Some("my_project"),
-            None,
-            None,
+            &ProjectsQuery {
+                client_id: Some(
+                    types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                        .expect("invalid UUID")
+                ),
+                project_type: Some(
+                    types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de")
+                        .expect("invalid UUID")
+                ),
+                status: Some(project::Status::InProgress),
+                name_contains: Some("my_project"),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("projects retrieval did not fail on auth")
@@ -42,3 +82,66 @@ async fn test_projects() {
         Some(reqwest::StatusCode::UNAUTHORIZED),
     )
 }
+
+#[tokio::test]
+async fn test_create_project() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        create_project(
+            &client,
+            &project::ProjectInput {
+                name: Some(String::from("Vendor Portal")),
+                client_id: Some(
+                    types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                        .expect("invalid UUID")
+                ),
+                billing_type_id: Some(project::BillingType::Fixed),
+                fixed_fee: Some(shared::Decimal::from(50000)),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("project creation did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    );
+    assert_eq!(
+        create_project(
+            &client,
+            &project::ProjectInput {
+                name: Some(String::from("Vendor Portal")),
+                client_id: Some(
+                    types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                        .expect("invalid UUID")
+                ),
+                billing_type_id: Some(project::BillingType::Fixed),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("project creation did not fail on validation")
+        .to_string(),
+        "fixed_fee is required for fixed billing type",
+    )
+}
+
+#[tokio::test]
+async fn test_update_project() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        update_project(
+            &client,
+            types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID"),
+            &project::ProjectInput {
+                status_id: Some(project::Status::Completed),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("project update did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
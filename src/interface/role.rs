@@ -3,56 +3,114 @@
 //! `interface::role` consists of functions for interfacing with the Ruddr Role endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::role;
-use crate::model::types;
+use crate::client::{client, error, resource};
+use crate::model::{project, role, types};
 
 /// Retrieves a specific Ruddr Role object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/projects/get-a-project-role.md)
 /// ```ignore
-/// let role = role(&client, types::UUID::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354").expect("uuid conversion failed")).await?;
+/// let role = role(&client, types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354").expect("uuid conversion failed")).await?;
 /// ```
-pub async fn role(client: &client::Client, id: types::UUID) -> Result<role::Role, reqwest::Error> {
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn role(
+    client: &client::Client,
+    id: types::Id<role::Role>,
+) -> Result<role::Role, error::Error> {
     // retrieve role
-    Ok(client
-        .read::<role::Role>(&format!("project-roles/{id}"), None)
-        .await?)
+    client.get::<role::Role>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Role objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Role objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = RolesQuery {
+///     project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("uuid conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct RolesQuery {
+    pub project: Option<types::Id<project::Project>>,
+    pub starting_after: Option<types::Id<role::Role>>,
+    pub ending_before: Option<types::Id<role::Role>>,
+}
+
+impl resource::Query for RolesQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(project) = &self.project {
+            write!(params, "&projectId={}", project).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Role objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/project-roles/list-project-roles.md)
 /// ```ignore
 /// let roles = roles(
 ///     &client,
-///     Some(types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("uuid conversion failed")),
-///     None,
-///     None,
+///     &RolesQuery {
+///         project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("uuid conversion failed")),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn roles(
     client: &client::Client,
-    project: Option<types::UUID>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<role::Roles, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(project) = project {
-        write!(params, "&projectId={}", project).unwrap();
+    query: &RolesQuery,
+) -> Result<role::Roles, error::Error> {
+    // retrieve roles
+    client.list::<role::Role>(query).await
+}
+
+/// Retrieves specific Ruddr Role objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = roles_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn roles_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<role::Role>>,
+    concurrency: usize,
+) -> resource::Batch<role::Role> {
+    // retrieve roles
+    client.get_many::<role::Role>(ids, concurrency).await
+}
+
+impl resource::Resource for role::Role {
+    const ENDPOINT: &'static str = "project-roles";
+    type List = role::Roles;
+    type Query<'query> = RolesQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<role::Role> for role::Roles {
+    fn results(&self) -> &[role::Role] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<role::Role> {
+        self.results
     }
 
-    // retrieve roles
-    Ok(client
-        .read::<role::Roles>("project-roles", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_role() {
@@ -7,7 +8,7 @@ async fn test_role() {
     assert_eq!(
         role(
             &client,
-            types::UUID::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354")
+            types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354")
                 .expect("uuid conversion failed")
         )
         .await
@@ -17,6 +18,26 @@ async fn test_role() {
     )
 }
 
+#[test]
+fn test_roles_query_params() {
+    assert_eq!(RolesQuery::default().params(), "limit=100");
+    assert_eq!(
+        RolesQuery {
+            project: Some(
+                types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                    .expect("uuid conversion failed")
+            ),
+            ending_before: Some(
+                types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
+                    .expect("uuid conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&projectId=095e0780-48bf-472c-8deb-2fc3ebc7d90c&endingBefore=b3a100b0-8e71-4f39-9d96-32f11838aa8c",
+    )
+}
+
 #[tokio::test]
 async fn test_roles() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -24,12 +45,13 @@ async fn test_roles() {
     assert_eq!(
         roles(
             &client,
-            Some(
-                types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
+            &RolesQuery {
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("roles retrieval did not fail on auth")
@@ -3,98 +3,190 @@
 //! `interface::time` consists of functions for interfacing with the Ruddr Time Entry endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{time, types};
+use crate::client::{client, error, resource};
+use crate::model::{self, member, project, role, shared, time, types};
 
 /// Retrieves a specific Ruddr Time Entry object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/time-entries/get-a-time-entry.md)
 /// ```ignore
-/// let time_entry = time_entry(&client, types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")).await?;
+/// let time_entry = time_entry(&client, types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn time_entry(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<time::TimeEntry, reqwest::Error> {
+    id: types::Id<time::TimeEntry>,
+) -> Result<time::TimeEntry, error::Error> {
     // retrieve time entry
-    Ok(client
-        .read::<time::TimeEntry>(&format!("time-entries/{id}"), None)
-        .await?)
+    client.get::<time::TimeEntry>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Time Entry objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Time Entry objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = TimeEntriesQuery {
+///     member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///     status: Some(shared::Status::Approved),
+///     is_billable: Some(true),
+///     begin_date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct TimeEntriesQuery {
+    pub member: Option<types::Id<member::Member>>,
+    pub project: Option<types::Id<project::Project>>,
+    pub client_id: Option<types::Id<model::client::Client>>,
+    pub role: Option<types::Id<role::Role>>,
+    pub task: Option<types::UUID>,
+    pub timesheet: Option<types::UUID>,
+    pub time_off_type: Option<types::UUID>,
+    pub practice: Option<types::UUID>,
+    pub time_type: Option<time::Type>,
+    pub status: Option<shared::Status>,
+    pub client_status: Option<time::ClientStatus>,
+    pub is_billable: Option<bool>,
+    pub invoiced: Option<bool>,
+    pub date: Option<types::Date>,
+    pub date_after: Option<types::Date>,
+    pub begin_date: Option<types::Date>,
+    pub date_before: Option<types::Date>,
+    pub end_date: Option<types::Date>,
+    pub starting_after: Option<types::Id<time::TimeEntry>>,
+    pub ending_before: Option<types::Id<time::TimeEntry>>,
+}
+
+impl resource::Query for TimeEntriesQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(project) = &self.project {
+            write!(params, "&projectId={}", project).unwrap();
+        }
+        if let Some(client_id) = &self.client_id {
+            write!(params, "&clientId={}", client_id).unwrap();
+        }
+        if let Some(role) = &self.role {
+            write!(params, "&roleId={}", role).unwrap();
+        }
+        if let Some(task) = &self.task {
+            write!(params, "&taskId={}", task).unwrap();
+        }
+        if let Some(date) = &self.date {
+            write!(params, "&date={}", date).unwrap();
+        }
+        if let Some(date_after) = &self.date_after {
+            write!(params, "&dateAfter={}", date_after).unwrap();
+        }
+        if let Some(begin_date) = &self.begin_date {
+            write!(params, "&dateOnAfter={}", begin_date).unwrap();
+        }
+        if let Some(date_before) = &self.date_before {
+            write!(params, "&dateBefore={}", date_before).unwrap();
+        }
+        if let Some(end_date) = &self.end_date {
+            write!(params, "&dateOnBefore={}", end_date).unwrap();
+        }
+        if let Some(timesheet) = &self.timesheet {
+            write!(params, "&timesheetId={}", timesheet).unwrap();
+        }
+        if let Some(time_off_type) = &self.time_off_type {
+            write!(params, "&timeOffTypeId={}", time_off_type).unwrap();
+        }
+        if let Some(practice) = &self.practice {
+            write!(params, "&practiceId={}", practice).unwrap();
+        }
+        if let Some(time_type) = &self.time_type {
+            write!(params, "&typeId={}", time_type).unwrap();
+        }
+        if let Some(status) = &self.status {
+            write!(params, "&statusId={}", status).unwrap();
+        }
+        if let Some(client_status) = &self.client_status {
+            write!(params, "&clientStatusId={}", client_status).unwrap();
+        }
+        if let Some(is_billable) = self.is_billable {
+            write!(params, "&isBillable={}", is_billable).unwrap();
+        }
+        if let Some(invoiced) = self.invoiced {
+            write!(params, "&invoiced={}", invoiced).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Time Entry objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/time-entries/list-time-entries.md)
 /// ```ignore
 /// let time_entries = time_entries(
 ///     &client,
-///     Some(types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
-///     Some(types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")),
-///     Some(types::UUID::try_from("c4b8d9e6-1f0a-4f5f-9d2e-8e4c8b0a1f2d").expect("invalid UUID")),
-///     Some(time::Type::ProjectTime),
-///     Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-///     Some(types::Date::try_from("2024-01-02").expect("date conversion failed")),
-///     Some(types::Date::try_from("2024-01-03").expect("date conversion failed")),
-///     None,
-///     None,
-///     None,
-///     None,
+///     &TimeEntriesQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///         project: Some(types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c").expect("invalid UUID")),
+///         time_type: Some(time::Type::ProjectTime),
+///         client_status: Some(time::ClientStatus::Approved),
+///         invoiced: Some(false),
+///         begin_date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn time_entries(
     client: &client::Client,
-    member: Option<types::UUID>,
-    project: Option<types::UUID>,
-    timesheet: Option<types::UUID>,
-    time_type: Option<time::Type>,
-    date: Option<types::Date>,
-    date_after: Option<types::Date>,
-    begin_date: Option<types::Date>,
-    date_before: Option<types::Date>,
-    end_date: Option<types::Date>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<time::TimeEntries, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
+    query: &TimeEntriesQuery,
+) -> Result<time::TimeEntries, error::Error> {
+    // retrieve time entries
+    client.list::<time::TimeEntry>(query).await
+}
 
-    // optional parameters for LIST
-    if let Some(member) = member {
-        write!(params, "&memberId={}", member).unwrap();
-    }
-    if let Some(project) = project {
-        write!(params, "&projectId={}", project).unwrap();
-    }
-    if let Some(date) = date {
-        write!(params, "&date={}", date).unwrap();
-    }
-    if let Some(date_after) = date_after {
-        write!(params, "&dateAfter={}", date_after).unwrap();
-    }
-    if let Some(begin_date) = begin_date {
-        write!(params, "&dateOnAfter={}", begin_date).unwrap();
-    }
-    if let Some(date_before) = date_before {
-        write!(params, "&dateBefore={}", date_before).unwrap();
-    }
-    if let Some(end_date) = end_date {
-        write!(params, "&dateOnBefore={}", end_date).unwrap();
-    }
-    if let Some(timesheet) = timesheet {
-        write!(params, "&timesheetId={}", timesheet).unwrap();
-    }
-    if let Some(time_type) = time_type {
-        write!(params, "&typeId={}", time_type).unwrap();
+/// Retrieves specific Ruddr Time entry objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = time_entries_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn time_entries_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<time::TimeEntry>>,
+    concurrency: usize,
+) -> resource::Batch<time::TimeEntry> {
+    // retrieve time entries
+    client.get_many::<time::TimeEntry>(ids, concurrency).await
+}
+
+impl resource::Resource for time::TimeEntry {
+    const ENDPOINT: &'static str = "time-entries";
+    type List = time::TimeEntries;
+    type Query<'query> = TimeEntriesQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<time::TimeEntry> for time::TimeEntries {
+    fn results(&self) -> &[time::TimeEntry] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<time::TimeEntry> {
+        self.results
     }
 
-    // retrieve time entries
-    Ok(client
-        .read::<time::TimeEntries>("time-entries", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
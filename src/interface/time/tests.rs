@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_time_entry() {
@@ -7,7 +8,7 @@ async fn test_time_entry() {
     assert_eq!(
         time_entry(
             &client,
-            types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")
+            types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")
         )
         .await
         .expect_err("time entry retrieval did not fail on auth")
@@ -16,6 +17,30 @@ async fn test_time_entry() {
     )
 }
 
+#[test]
+fn test_time_entries_query_params() {
+    assert_eq!(TimeEntriesQuery::default().params(), "limit=100");
+    assert_eq!(
+        TimeEntriesQuery {
+            member: Some(
+                types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")
+            ),
+            client_id: Some(
+                types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")
+            ),
+            time_type: Some(time::Type::TimeOff),
+            status: Some(shared::Status::PendingApproval),
+            client_status: Some(time::ClientStatus::NotCreated),
+            is_billable: Some(true),
+            invoiced: Some(false),
+            begin_date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&memberId=ec5543de-3b0f-47a0-b8ef-a6e18dc4b885&clientId=d5afaffe-09e5-4d73-b02c-905b40fc6c22&dateOnAfter=2024-01-01&typeId=time_off&statusId=pending_approval&clientStatusId=not_created&isBillable=true&invoiced=false",
+    )
+}
+
 #[tokio::test]
 async fn test_time_entries() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -23,23 +48,21 @@ async fn test_time_entries() {
     assert_eq!(
         time_entries(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("invalid UUID")
-            ),
-            Some(
-                types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
-                    .expect("invalid UUID")
-            ),
-            None,
-            None,
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            None,
-            None,
-            None,
+            &TimeEntriesQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("invalid UUID")
+                ),
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("invalid UUID")
+                ),
+                date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+                begin_date: Some(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("time entries retrieval did not fail on auth")
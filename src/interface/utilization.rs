@@ -3,58 +3,116 @@
 //! `interface::utilization` consists of functions for interfacing with the Ruddr Utilization target period endpoints.
 use std::fmt::Write;
 
-use crate::client::client;
-use crate::model::{types, utilization};
+use crate::client::{client, error, resource};
+use crate::model::{member, types, utilization};
 
 /// Retrieves a specific Ruddr Utilization target period object by id, and deserializes it to the corresponding model struct.
 /// [API Documentation](https://docs.ruddr.io/api-reference/utilization-target-periods/get-a-utilization-target-period.md)
 /// ```ignore
-/// let utilization = utilization(&client, types::UUID::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab").expect("invalid UUID")).await?;
+/// let utilization = utilization(&client, types::Id::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab").expect("invalid UUID")).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn utilization(
     client: &client::Client,
-    id: types::UUID,
-) -> Result<utilization::Utilization, reqwest::Error> {
+    id: types::Id<utilization::Utilization>,
+) -> Result<utilization::Utilization, error::Error> {
     // retrieve utilization target period
-    Ok(client
-        .read::<utilization::Utilization>(&format!("utilization-target-periods/{id}"), None)
-        .await?)
+    client.get::<utilization::Utilization>(id).await
 }
 
-/// Retrieves the first 100 Ruddr Utilization target period objects by filters, and deserializes it to the corresponding vector of model structs.
+/// Query filters for List operations on Ruddr Utilization target period objects. Unspecified filters are omitted from the request.
+/// ```ignore
+/// let query = UtilizationsQuery {
+///     member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("uuid conversion failed")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct UtilizationsQuery {
+    pub member: Option<types::Id<member::Member>>,
+    pub starting_after: Option<types::Id<utilization::Utilization>>,
+    pub ending_before: Option<types::Id<utilization::Utilization>>,
+}
+
+impl resource::Query for UtilizationsQuery {
+    // construct params from filters with their Ruddr query names
+    fn params(&self) -> String {
+        // initialize params
+        let mut params = String::from("limit=100");
+
+        // optional parameters for LIST
+        if let Some(member) = &self.member {
+            write!(params, "&memberId={}", member).unwrap();
+        }
+        if let Some(starting_after) = &self.starting_after {
+            write!(params, "&startingAfter={}", starting_after).unwrap();
+        }
+        if let Some(ending_before) = &self.ending_before {
+            write!(params, "&endingBefore={}", ending_before).unwrap();
+        }
+
+        params
+    }
+}
+
+/// Retrieves the first 100 Ruddr Utilization target period objects by query filters, and deserializes it to the corresponding vector of model structs.
 /// [API Documentation](https://docs.ruddr.io/api-reference/utilization-target-periods/list-utilization-target-periods.md)
 /// ```ignore
 /// let utilizations = utilizations(
 ///     &client,
-///     Some(types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
-///     None,
-///     None,
+///     &UtilizationsQuery {
+///         member: Some(types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885").expect("invalid UUID")),
+///         ..Default::default()
+///     },
 /// ).await?;
 /// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn utilizations(
     client: &client::Client,
-    member: Option<types::UUID>,
-    starting_after: Option<types::UUID>,
-    ending_before: Option<types::UUID>,
-) -> Result<utilization::Utilizations, reqwest::Error> {
-    // initialize params
-    let mut params = String::from("limit=100");
-
-    // optional parameters for LIST
-    if let Some(member) = member {
-        write!(params, "&memberId={}", member).unwrap();
+    query: &UtilizationsQuery,
+) -> Result<utilization::Utilizations, error::Error> {
+    // retrieve utilization target periods
+    client.list::<utilization::Utilization>(query).await
+}
+
+/// Retrieves specific Ruddr Utilization target period objects by ids with at most `concurrency` concurrent requests, and deserializes them to the corresponding map of model structs by id. Duplicate ids are retrieved once, and failures such as nonexistent objects are returned per id.
+/// ```ignore
+/// let batch = utilizations_by_ids(&client, ids, 8).await;
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn utilizations_by_ids(
+    client: &client::Client,
+    ids: impl IntoIterator<Item = types::Id<utilization::Utilization>>,
+    concurrency: usize,
+) -> resource::Batch<utilization::Utilization> {
+    // retrieve utilization target periods
+    client
+        .get_many::<utilization::Utilization>(ids, concurrency)
+        .await
+}
+
+impl resource::Resource for utilization::Utilization {
+    const ENDPOINT: &'static str = "utilization-target-periods";
+    type List = utilization::Utilizations;
+    type Query<'query> = UtilizationsQuery;
+
+    fn id(&self) -> &types::Id<Self> {
+        &self.id
     }
-    if let Some(starting_after) = starting_after {
-        write!(params, "&startingAfter={}", starting_after).unwrap();
+}
+
+impl resource::Page<utilization::Utilization> for utilization::Utilizations {
+    fn results(&self) -> &[utilization::Utilization] {
+        &self.results
     }
-    if let Some(ending_before) = ending_before {
-        write!(params, "&endingBefore={}", ending_before).unwrap();
+
+    fn into_results(self) -> Vec<utilization::Utilization> {
+        self.results
     }
 
-    // retrieve utilization target periods
-    Ok(client
-        .read::<utilization::Utilizations>("utilization-target-periods", Some(&params))
-        .await?)
+    fn has_more(&self) -> bool {
+        self.has_more
+    }
 }
 
 #[cfg(test)]
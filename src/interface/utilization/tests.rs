@@ -1,4 +1,5 @@
 use super::*;
+use crate::client::resource::Query;
 
 #[tokio::test]
 async fn test_utilization() {
@@ -7,7 +8,7 @@ async fn test_utilization() {
     assert_eq!(
         utilization(
             &client,
-            types::UUID::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab").expect("invalid UUID")
+            types::Id::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab").expect("invalid UUID")
         )
         .await
         .expect_err("utilization retrieval did not fail on auth")
@@ -16,6 +17,26 @@ async fn test_utilization() {
     )
 }
 
+#[test]
+fn test_utilizations_query_params() {
+    assert_eq!(UtilizationsQuery::default().params(), "limit=100");
+    assert_eq!(
+        UtilizationsQuery {
+            member: Some(
+                types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                    .expect("uuid conversion failed")
+            ),
+            ending_before: Some(
+                types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
+                    .expect("uuid conversion failed")
+            ),
+            ..Default::default()
+        }
+        .params(),
+        "limit=100&memberId=ec5543de-3b0f-47a0-b8ef-a6e18dc4b885&endingBefore=b3a100b0-8e71-4f39-9d96-32f11838aa8c",
+    )
+}
+
 #[tokio::test]
 async fn test_utilizations() {
     let client = client::Client::new(Some("abcdefghi123456789"))
@@ -23,12 +44,13 @@ async fn test_utilizations() {
     assert_eq!(
         utilizations(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("invalid UUID")
-            ),
-            None,
-            None,
+            &UtilizationsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("invalid UUID")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("utilizations retrieval did not fail on auth")
@@ -1,6 +1,28 @@
+#[cfg(feature = "blocking")]
+pub mod blocking {
+    pub mod client;
+    pub use client::Client;
+    pub mod interface {
+        pub mod allocation;
+        pub mod cost;
+        pub mod customer;
+        pub mod expense_item;
+        pub mod expense_report;
+        pub mod member;
+        pub mod project;
+        pub mod role;
+        pub mod time;
+        pub mod utilization;
+    }
+}
 pub mod client {
+    pub mod cache;
     pub mod client;
-    mod request;
+    pub mod error;
+    pub mod metrics;
+    pub(crate) mod request;
+    pub mod resource;
+    pub(crate) mod trace;
 }
 pub mod interface {
     pub mod allocation;
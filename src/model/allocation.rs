@@ -2,7 +2,7 @@
 //!
 //! `model::allocation` is a model for the Ruddr Allocation object. This module is not publically accessible, but the structs and members are public for reading from `interface::allocation` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/allocations/get-an-allocation.md)
-use crate::model::{shared, types};
+use crate::model::{member, role, shared, types};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -18,7 +18,7 @@ pub struct Allocations {
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Allocation {
-    pub id: types::UUID,
+    pub id: types::Id<Allocation>,
     pub resource_type_id: ResourceType,
     pub assignment_type_id: AssignmentType,
     pub start: types::Date,
@@ -37,12 +37,15 @@ pub struct Allocation {
     pub read_only: bool,
     pub entity: Entity,
     pub created_at: types::Timestamp,
-    pub member: Option<shared::Entity>,
+    pub member: Option<shared::Entity<member::Member>>,
     pub placeholder: Option<shared::Entity>,
     pub project: Option<shared::Project>,
-    pub role: Option<shared::Entity>,
+    pub role: Option<shared::Entity<role::Role>>,
     pub task: Option<shared::Entity>,
     pub time_off_type: Option<shared::Entity>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Allocation, _>")]
+    pub extra: shared::Extra,
 }
 
 // custom types: enum
@@ -51,6 +54,20 @@ pub struct Allocation {
 pub enum ResourceType {
     Member,
     Placeholder,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -58,6 +75,9 @@ pub enum ResourceType {
 pub enum AssignmentType {
     Project,
     TimeOff,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl fmt::Display for AssignmentType {
@@ -79,6 +99,9 @@ pub enum Unit {
     Month,
     Allocation,
     RatioOfCapacity,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -86,6 +109,20 @@ pub enum Unit {
 pub enum BookingType {
     Confirmed,
     Soft,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for BookingType {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -94,6 +131,9 @@ pub enum Entity {
     Allocation,
     TimeEntry,
     Holiday,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
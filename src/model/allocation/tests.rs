@@ -55,7 +55,8 @@ fn test_allocation_deserialize() {
     let allocation_deserialized = serde_json::from_str::<Allocation>(json_input)
         .expect("allocation could not be deserialized");
     let allocation = Allocation {
-        id: types::UUID(String::from("212b8272-ed2a-4a91-950a-8a06b3546144")),
+        id: types::Id::try_from("212b8272-ed2a-4a91-950a-8a06b3546144")
+            .expect("uuid conversion failed"),
         resource_type_id: ResourceType::Placeholder,
         assignment_type_id: AssignmentType::Project,
         start: types::Date(String::from("2022-06-01")),
@@ -73,35 +74,51 @@ fn test_allocation_deserialize() {
         notes: None,
         read_only: false,
         entity: Entity::Allocation,
-        created_at: types::Timestamp(String::from("2022-03-02T17:40:03.633Z")),
+        created_at: types::Timestamp::try_from("2022-03-02T17:40:03.633Z")
+            .expect("timestamp conversion failed"),
         member: Some(shared::Entity {
-            id: types::UUID(String::from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")),
+            id: types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                .expect("uuid conversion failed"),
             name: String::from("John Smith"),
+            extra: shared::Extra::new(),
         }),
         placeholder: Some(shared::Entity {
-            id: types::UUID(String::from("e804cd57-8b98-437d-86f4-90e920a41774")),
+            id: types::Id::try_from("e804cd57-8b98-437d-86f4-90e920a41774")
+                .expect("uuid conversion failed"),
             name: String::from("Project Manager"),
+            extra: shared::Extra::new(),
         }),
         project: Some(shared::Project {
-            id: types::UUID(String::from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")),
+            id: types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                .expect("uuid conversion failed"),
             name: String::from("Vendor Portal"),
             client: shared::Entity {
-                id: types::UUID(String::from("4cacdf11-71d1-4fbb-90ee-b091803581b0")),
+                id: types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+                    .expect("uuid conversion failed"),
                 name: String::from("Joe's Shop"),
+                extra: shared::Extra::new(),
             },
+            extra: shared::Extra::new(),
         }),
         role: Some(shared::Entity {
-            id: types::UUID(String::from("7ad5a34a-07b7-48e9-a760-bd220d52e354")),
+            id: types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354")
+                .expect("uuid conversion failed"),
             name: String::from("Project Manager"),
+            extra: shared::Extra::new(),
         }),
         task: Some(shared::Entity {
-            id: types::UUID(String::from("9a7097a0-d71e-4ed2-9bc3-2dd7d797edc4")),
+            id: types::Id::try_from("9a7097a0-d71e-4ed2-9bc3-2dd7d797edc4")
+                .expect("uuid conversion failed"),
             name: String::from("Project Status Reporting"),
+            extra: shared::Extra::new(),
         }),
         time_off_type: Some(shared::Entity {
-            id: types::UUID(String::from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")),
+            id: types::Id::try_from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")
+                .expect("uuid conversion failed"),
             name: String::from("Holiday"),
+            extra: shared::Extra::new(),
         }),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         allocation_deserialized, allocation,
@@ -129,3 +146,32 @@ fn test_allocation_deserialize() {
 fn test_assignment_type_display() {
     assert_eq!(AssignmentType::TimeOff.to_string(), "time_off")
 }
+
+#[test]
+fn test_resource_type_display() {
+    assert_eq!(ResourceType::Placeholder.to_string(), "placeholder")
+}
+
+#[test]
+fn test_booking_type_display() {
+    assert_eq!(BookingType::Confirmed.to_string(), "confirmed")
+}
+
+#[test]
+fn test_unknown_variants() {
+    let unit = serde_json::from_str::<Unit>("\"quarter\"").expect("unit could not be deserialized");
+    assert_eq!(unit, Unit::Unknown(String::from("quarter")));
+    assert_eq!(
+        serde_json::to_string(&unit).expect("unit could not be serialized"),
+        "\"quarter\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Unit>("\"ratio_of_capacity\"")
+            .expect("unit could not be deserialized"),
+        Unit::RatioOfCapacity
+    );
+    assert_eq!(
+        BookingType::Unknown(String::from("tentative")).to_string(),
+        "tentative"
+    )
+}
@@ -2,7 +2,7 @@
 //!
 //! `model::client` is a model for the Ruddr Client object. This module is not publically accessible, but the structs and members are public for reading from `interface::client` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/clients/get-a-client.md)
-use crate::model::{shared, types};
+use crate::model::{member, shared, types};
 use serde::{Deserialize, Serialize};
 
 /// Model for Clients used with List operations.
@@ -17,11 +17,11 @@ pub struct Clients {
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Client {
-    pub id: types::UUID,
+    pub id: types::Id<Client>,
     pub key: String,
     pub name: String,
     pub code: Option<String>,
-    pub currency: String,
+    pub currency: shared::Currency,
     pub notes: Option<String>,
     pub emails: Vec<String>,
     pub street_address: Option<String>,
@@ -38,11 +38,14 @@ pub struct Client {
     pub industry: Option<shared::Entity>,
     pub location: Option<shared::Entity>,
     pub invoice_payment_term: Option<shared::Entity>,
-    pub owner: Option<shared::Entity>,
+    pub owner: Option<shared::Entity<member::Member>>,
     pub tags: Vec<shared::Entity>,
-    pub sales_representative: Option<shared::Entity>,
+    pub sales_representative: Option<shared::Entity<member::Member>>,
     pub business_unit: Option<shared::Entity>,
     pub integrations: Vec<Integration>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Client, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -52,6 +55,97 @@ pub struct Integration {
     pub integration_type: IntegrationType,
     pub connection_id: types::UUID,
     pub external_id: String,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Integration, _>")]
+    pub extra: shared::Extra,
+}
+
+/// Model for Client input used with Create and Update operations.
+/// Unspecified members are omitted from the request body so that Update operations only modify the specified members.
+/// ```ignore
+/// let client_input = ClientInput {
+///     name: Some(String::from("Joe's Shop")),
+///     currency: Some(shared::Currency::Usd),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<shared::Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emails: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_details_source: Option<InvoiceDetailsSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_email_subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_email_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_workspace_invoice_details: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_internal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_status_id: Option<shared::RecordStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub practice_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub industry_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_payment_term_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<types::Id<member::Member>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_ids: Option<Vec<types::UUID>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sales_representative_id: Option<types::Id<member::Member>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_unit_id: Option<types::UUID>,
+}
+
+impl ClientInput {
+    /// Validates the input for a Create operation, which additionally requires the members the Ruddr API requires for a new Client.
+    pub fn validate_create(&self) -> Result<(), shared::ValidationError> {
+        if self.name.is_none() {
+            return Err(shared::ValidationError(String::from(
+                "name is required to create a client",
+            )));
+        }
+        self.validate()
+    }
+
+    /// Validates the input for an Update operation, which only validates the specified members.
+    pub fn validate(&self) -> Result<(), shared::ValidationError> {
+        if let Some(name) = &self.name
+            && name.trim().is_empty()
+        {
+            return Err(shared::ValidationError(String::from(
+                "name must not be empty",
+            )));
+        }
+        if let Some(emails) = &self.emails
+            && let Some(email) = emails.iter().find(|email| !email.contains('@'))
+        {
+            return Err(shared::ValidationError(format!("invalid email: {email}")));
+        }
+
+        Ok(())
+    }
 }
 
 // custom types: enum
@@ -61,6 +155,9 @@ pub enum InvoiceDetailsSource {
     Workspace,
     Custom,
     BusinessUnit,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -68,6 +165,9 @@ pub enum InvoiceDetailsSource {
 pub enum IntegrationType {
     Xero,
     Qbo,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
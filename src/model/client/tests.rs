@@ -67,11 +67,12 @@ fn test_client_deserialize() {
     let client_deserialized =
         serde_json::from_str::<Client>(json_input).expect("client could not be deserialized");
     let client = Client {
-        id: types::UUID(String::from("4cacdf11-71d1-4fbb-90ee-b091803581b0")),
+        id: types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+            .expect("uuid conversion failed"),
         key: String::from("joes-shop"),
         name: String::from("Joe's Shop"),
         code: None,
-        currency: String::from("USD"),
+        currency: shared::Currency::Usd,
         notes: None,
         emails: vec![
             String::from("joe@joesshop.com"),
@@ -86,44 +87,62 @@ fn test_client_deserialize() {
         invoice_notes: None,
         is_internal: false,
         record_status_id: shared::RecordStatus::Active,
-        created_at: types::Timestamp(String::from("2022-02-24T16:08:18.640Z")),
+        created_at: types::Timestamp::try_from("2022-02-24T16:08:18.640Z")
+            .expect("timestamp conversion failed"),
         practice: Some(shared::Entity {
-            id: types::UUID(String::from("40f95471-7f7c-4ffa-b838-8dcccab0f54a")),
+            id: types::Id::try_from("40f95471-7f7c-4ffa-b838-8dcccab0f54a")
+                .expect("uuid conversion failed"),
             name: String::from("Digital Transformation"),
+            extra: shared::Extra::new(),
         }),
         industry: Some(shared::Entity {
-            id: types::UUID(String::from("bc52e68a-96d9-46bd-881c-bda8d5e07053")),
+            id: types::Id::try_from("bc52e68a-96d9-46bd-881c-bda8d5e07053")
+                .expect("uuid conversion failed"),
             name: String::from("Technology"),
+            extra: shared::Extra::new(),
         }),
         location: Some(shared::Entity {
-            id: types::UUID(String::from("ae469b03-51f3-47c2-be12-a319e0ce7cd8")),
+            id: types::Id::try_from("ae469b03-51f3-47c2-be12-a319e0ce7cd8")
+                .expect("uuid conversion failed"),
             name: String::from("New York"),
+            extra: shared::Extra::new(),
         }),
         invoice_payment_term: None,
         owner: None,
         tags: vec![
             shared::Entity {
-                id: types::UUID(String::from("8670e0fd-bd7a-457e-bec9-eff2b1c12b78")),
+                id: types::Id::try_from("8670e0fd-bd7a-457e-bec9-eff2b1c12b78")
+                    .expect("uuid conversion failed"),
                 name: String::from("Tier 1 Client"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("032901d9-4a10-4ff7-af3a-a04ff6e6e606")),
+                id: types::Id::try_from("032901d9-4a10-4ff7-af3a-a04ff6e6e606")
+                    .expect("uuid conversion failed"),
                 name: String::from("Mid-Atlantic Region"),
+                extra: shared::Extra::new(),
             },
         ],
         sales_representative: Some(shared::Entity {
-            id: types::UUID(String::from("c6f2b081-d47a-4e93-a8d5-3e1c7f9a204b")),
+            id: types::Id::try_from("c6f2b081-d47a-4e93-a8d5-3e1c7f9a204b")
+                .expect("uuid conversion failed"),
             name: String::from("John Davis"),
+            extra: shared::Extra::new(),
         }),
         business_unit: Some(shared::Entity {
-            id: types::UUID(String::from("0e8351ea-6b3c-4307-97cc-196448de0ef1")),
+            id: types::Id::try_from("0e8351ea-6b3c-4307-97cc-196448de0ef1")
+                .expect("uuid conversion failed"),
             name: String::from("EU"),
+            extra: shared::Extra::new(),
         }),
         integrations: vec![Integration {
             integration_type: IntegrationType::Xero,
-            connection_id: types::UUID(String::from("9d2f6b1a-4c83-4e57-bf09-1a6d3e8c20b4")),
+            connection_id: types::UUID::try_from("9d2f6b1a-4c83-4e57-bf09-1a6d3e8c20b4")
+                .expect("uuid conversion failed"),
             external_id: String::from("5e1a9c47-2b86-4d30-9f12-7a4c8e3b50d9"),
+            extra: shared::Extra::new(),
         }],
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         client_deserialized, client,
@@ -146,3 +165,49 @@ fn test_client_deserialize() {
         "clients did not contain the expected values"
     );
 }
+
+#[test]
+fn test_client_input_serialize() {
+    let client_input = ClientInput {
+        name: Some(String::from("Joe's Shop")),
+        currency: Some(shared::Currency::Usd),
+        record_status_id: Some(shared::RecordStatus::Active),
+        owner_id: Some(
+            types::Id::try_from("a6816355-8945-40aa-b798-b0d6fd89e437")
+                .expect("uuid conversion failed"),
+        ),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_string(&client_input).expect("client input could not be serialized"),
+        r#"{"name":"Joe's Shop","currency":"USD","recordStatusId":"active","ownerId":"a6816355-8945-40aa-b798-b0d6fd89e437"}"#,
+        "client input did not serialize to the expected body"
+    );
+}
+
+#[test]
+fn test_client_input_validate() {
+    assert_eq!(
+        ClientInput::default().validate_create().unwrap_err(),
+        shared::ValidationError(String::from("name is required to create a client"))
+    );
+    assert_eq!(
+        ClientInput {
+            name: Some(String::from(" ")),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err(),
+        shared::ValidationError(String::from("name must not be empty"))
+    );
+    assert_eq!(
+        ClientInput {
+            emails: Some(vec![String::from("joe.example.com")]),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err(),
+        shared::ValidationError(String::from("invalid email: joe.example.com"))
+    );
+    assert!(ClientInput::default().validate().is_ok());
+}
@@ -17,19 +17,78 @@ pub struct Costs {
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Cost {
-    pub id: types::UUID,
+    pub id: types::Id<Cost>,
     pub is_default: bool,
     pub start: types::Date,
     pub end: Option<types::Date>,
-    pub currency: String,
+    pub currency: shared::Currency,
     pub cost_method_id: shared::CostMethod,
-    pub cost_per_hour: Option<f64>,
-    pub overhead_cost_per_hour: Option<f64>,
-    pub total_cost_per_hour: Option<f64>,
-    pub cost_per_month: Option<f64>,
-    pub overhead_cost_per_month: Option<f64>,
-    pub total_cost_per_month: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost_per_hour: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub overhead_cost_per_hour: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_cost_per_hour: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost_per_month: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub overhead_cost_per_month: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_cost_per_month: Option<shared::Decimal>,
     pub created_at: types::Timestamp,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Cost, _>")]
+    pub extra: shared::Extra,
+}
+
+impl Cost {
+    /// Returns the cost per hour with its currency.
+    pub fn cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the overhead cost per hour with its currency.
+    pub fn overhead_cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.overhead_cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the total cost per hour with its currency.
+    pub fn total_cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.total_cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the cost per month with its currency.
+    pub fn cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the overhead cost per month with its currency.
+    pub fn overhead_cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.overhead_cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the total cost per month with its currency.
+    pub fn total_cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.total_cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -21,19 +21,22 @@ fn test_cost_deserialize() {
     let cost_deserialized =
         serde_json::from_str::<Cost>(json_input).expect("cost could not be deserialized");
     let cost = Cost {
-        id: types::UUID(String::from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")),
+        id: types::Id::try_from("b3a100b0-8e71-4f39-9d96-32f11838aa8c")
+            .expect("uuid conversion failed"),
         is_default: false,
         start: types::Date(String::from("2025-01-01")),
         end: None,
-        currency: String::from("USD"),
+        currency: shared::Currency::Usd,
         cost_method_id: shared::CostMethod::FixedMonthly,
         cost_per_hour: None,
         overhead_cost_per_hour: None,
         total_cost_per_hour: None,
-        cost_per_month: Some(12000.0),
-        overhead_cost_per_month: Some(3000.0),
-        total_cost_per_month: Some(15000.0),
-        created_at: types::Timestamp(String::from("2025-01-01T09:00:00.000Z")),
+        cost_per_month: Some(shared::Decimal::from(12000)),
+        overhead_cost_per_month: Some(shared::Decimal::from(3000)),
+        total_cost_per_month: Some(shared::Decimal::from(15000)),
+        created_at: types::Timestamp::try_from("2025-01-01T09:00:00.000Z")
+            .expect("timestamp conversion failed"),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         cost_deserialized, cost,
@@ -2,7 +2,7 @@
 //!
 //! `model::expense_item` is a model for the Ruddr Expense Item object. This module is not publically accessible, but the structs and members are public for reading from `interface::expense_item` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/expense-items/get-an-expense-item.md) (documentation has `Description` of `statusId` and `vendor` switched)
-use crate::model::{shared, types};
+use crate::model::{expense_report, member, shared, types};
 use serde::{Deserialize, Serialize};
 
 /// Model for ExpenseItems used with List operations.
@@ -17,36 +17,77 @@ pub struct ExpenseItems {
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ExpenseItem {
-    pub id: types::UUID,
+    pub id: types::Id<ExpenseItem>,
     pub status_id: shared::Status,
     pub vendor: Option<String>,
     pub notes: Option<String>,
     pub attendees: Option<String>,
     pub date: types::Date,
-    pub currency: String,
-    pub amount: f64,
+    pub currency: shared::Currency,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: shared::Decimal,
     pub markup_method: Option<MarkupMethod>,
     pub markup_ratio: Option<f64>,
-    pub markup_amount: Option<f64>,
-    pub markup: Option<f64>,
-    pub total: f64,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub markup_amount: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub markup: Option<shared::Decimal>,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub total: shared::Decimal,
     pub unit_count: Option<i64>,
-    pub unit_amount: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub unit_amount: Option<shared::Decimal>,
     pub is_reimbursable: bool,
     pub is_billable: bool,
     pub invoiced: bool,
     pub created_at: types::Timestamp,
     pub expense_report: ExpenseReport,
     pub expense_category: ExpenseCategory,
-    pub member: shared::Entity,
+    pub member: shared::Entity<member::Member>,
     pub project: Option<shared::Project>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<ExpenseItem, _>")]
+    pub extra: shared::Extra,
+}
+
+impl ExpenseItem {
+    /// Returns the amount with its currency.
+    pub fn amount_money(&self) -> shared::Money {
+        shared::Money::new(self.amount, self.currency.clone())
+    }
+
+    /// Returns the markup amount with its currency.
+    pub fn markup_amount_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.markup_amount?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the markup with its currency.
+    pub fn markup_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(self.markup?, self.currency.clone()))
+    }
+
+    /// Returns the total including markup with its currency.
+    pub fn total_money(&self) -> shared::Money {
+        shared::Money::new(self.total, self.currency.clone())
+    }
+
+    /// Returns the amount per unit with its currency.
+    pub fn unit_amount_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(self.unit_amount?, self.currency.clone()))
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ExpenseReport {
-    pub id: types::UUID,
+    pub id: types::Id<expense_report::ExpenseReport>,
     pub title: String,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<ExpenseReport, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -55,6 +96,9 @@ pub struct ExpenseCategory {
     pub id: types::UUID,
     pub name: String,
     pub unit_name: Option<String>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<ExpenseCategory, _>")]
+    pub extra: shared::Extra,
 }
 
 // custom types: enum
@@ -63,6 +107,9 @@ pub struct ExpenseCategory {
 pub enum MarkupMethod {
     Percentage,
     Amount,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -41,44 +41,60 @@ fn test_expense_item_deserialize() {
     let expense_item_deserialized = serde_json::from_str::<ExpenseItem>(json_input)
         .expect("expense_item could not be deserialized");
     let expense_item = ExpenseItem {
-        id: types::UUID(String::from("77f5ccdc-4226-4ff1-877e-5644d0a04522")),
+        id: types::Id::try_from("77f5ccdc-4226-4ff1-877e-5644d0a04522")
+            .expect("uuid conversion failed"),
         status_id: shared::Status::Approved,
         vendor: Some(String::from("Delta")),
         notes: Some(String::from("Flight to LAS")),
         attendees: Some(String::from("Alice Johnson, Bob Smith")),
         date: types::Date(String::from("2022-03-11")),
-        currency: String::from("USD"),
-        amount: 345.36,
+        currency: shared::Currency::Usd,
+        amount: shared::Decimal::new(34536, 2),
         markup_method: Some(MarkupMethod::Amount),
         markup_ratio: None,
-        markup_amount: Some(5.0),
-        markup: Some(5.0),
-        total: 350.36,
+        markup_amount: Some(shared::Decimal::from(5)),
+        markup: Some(shared::Decimal::from(5)),
+        total: shared::Decimal::new(35036, 2),
         unit_count: Some(23),
-        unit_amount: Some(0.575),
+        unit_amount: Some(shared::Decimal::new(575, 3)),
         is_reimbursable: true,
         is_billable: true,
         invoiced: false,
-        created_at: types::Timestamp(String::from("2022-03-18T16:51:51.148Z")),
+        created_at: types::Timestamp::try_from("2022-03-18T16:51:51.148Z")
+            .expect("timestamp conversion failed"),
         expense_report: ExpenseReport {
-            id: types::UUID(String::from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")),
+            id: types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+                .expect("uuid conversion failed"),
             title: String::from("Las Vegas Convention"),
+            extra: shared::Extra::new(),
         },
         expense_category: ExpenseCategory {
-            id: types::UUID(String::from("175e0635-ac9e-4880-8492-07fa584f1b15")),
+            id: types::UUID::try_from("175e0635-ac9e-4880-8492-07fa584f1b15")
+                .expect("uuid conversion failed"),
             name: String::from("Airfare"),
             unit_name: Some(String::from("mile")),
+            extra: shared::Extra::new(),
         },
         member: shared::Entity {
-            id: types::UUID(String::from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")),
+            id: types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                .expect("uuid conversion failed"),
             name: String::from("John Smith"),
+            extra: shared::Extra::new(),
         },
         project: None,
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         expense_item_deserialized, expense_item,
         "expense_item did not contain the expected values"
     );
+    assert_eq!(
+        expense_item
+            .amount_money()
+            .checked_add(&expense_item.markup_money().expect("markup is specified")),
+        Some(expense_item.total_money()),
+        "amount with markup did not exactly equal total"
+    );
 
     let json_inputs = &format!(
         r#"
@@ -2,7 +2,7 @@
 //!
 //! `model::expense_report` is models for the Ruddr Expense Report and Expense Item objects. This module is not publically accessible, but the structs and members are public for reading from `interface::expense_report` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/expense-reports/get-an-expense-report.md)
-use crate::model::{shared, types};
+use crate::model::{member, shared, types};
 use serde::{Deserialize, Serialize};
 
 /// Model for ExpenseReports used with List operations.
@@ -17,13 +17,16 @@ pub struct ExpenseReports {
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ExpenseReport {
-    pub id: types::UUID,
+    pub id: types::Id<ExpenseReport>,
     pub number: i64,
     pub title: String,
     pub notes: Option<String>,
     pub date: types::Date,
     pub created_at: types::Timestamp,
-    pub member: shared::Entity,
+    pub member: shared::Entity<member::Member>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<ExpenseReport, _>")]
+    pub extra: shared::Extra,
 }
 
 #[cfg(test)]
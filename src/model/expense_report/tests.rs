@@ -18,16 +18,21 @@ fn test_expense_report_deserialize() {
     let expense_report_deserialized = serde_json::from_str::<ExpenseReport>(json_input)
         .expect("expense_report could not be deserialized");
     let expense_report = ExpenseReport {
-        id: types::UUID(String::from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")),
+        id: types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+            .expect("uuid conversion failed"),
         number: 1000,
         title: String::from("Las Vegas Convention"),
         notes: None,
         date: types::Date(String::from("2022-03-11")),
-        created_at: types::Timestamp(String::from("2022-03-18T15:49:07.486Z")),
+        created_at: types::Timestamp::try_from("2022-03-18T15:49:07.486Z")
+            .expect("timestamp conversion failed"),
         member: shared::Entity {
-            id: types::UUID(String::from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")),
+            id: types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                .expect("uuid conversion failed"),
             name: String::from("John Smith"),
+            extra: shared::Extra::new(),
         },
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         expense_report_deserialized, expense_report,
@@ -2,8 +2,9 @@
 //!
 //! `model::member` is a model for the Ruddr Member object. This module is not publically accessible, but the structs and members are public for reading from `interface::member` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/members/get-a-member.md)
-use crate::model::{shared, types};
+use crate::model::{cost, shared, types, utilization};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Model for Members used with List operations.
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -17,7 +18,7 @@ pub struct Members {
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Member {
-    pub id: types::UUID,
+    pub id: types::Id<Member>,
     pub name: String,
     pub email: String,
     pub is_active: bool,
@@ -28,9 +29,10 @@ pub struct Member {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cost_method_id: Option<shared::CostMethod>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_rate: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub default_rate: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub default_rate_currency: Option<String>,
+    pub default_rate_currency: Option<shared::Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active_start_date: Option<types::Date>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,11 +66,11 @@ pub struct Member {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<shared::Entity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub manager: Option<shared::Entity>,
+    pub manager: Option<shared::Entity<Member>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub time_off_approver: Option<shared::Entity>,
+    pub time_off_approver: Option<shared::Entity<Member>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub internal_expense_approver: Option<shared::Entity>,
+    pub internal_expense_approver: Option<shared::Entity<Member>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub holiday_schedule: Option<shared::Entity>,
     pub tags: Vec<shared::Entity>,
@@ -81,6 +83,19 @@ pub struct Member {
     pub utilization_target_periods: Option<Vec<UtilizationTargetPeriod>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub forbid_timesheet_submission_when_below_capacity: Option<bool>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Member, _>")]
+    pub extra: shared::Extra,
+}
+
+impl Member {
+    /// Returns the default billable rate per hour with its currency.
+    pub fn default_rate_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.default_rate?,
+            self.default_rate_currency.clone()?,
+        ))
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -92,43 +107,111 @@ pub struct AvailabilityPeriod {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<types::Date>,
     pub hours_per_day: Vec<i64>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<AvailabilityPeriod, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct CostPeriod {
-    pub id: types::UUID,
+    pub id: types::Id<cost::Cost>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<types::Date>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<types::Date>,
-    pub currency: String,
+    pub currency: shared::Currency,
     pub cost_method_id: shared::CostMethod,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_per_hour: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost_per_hour: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub overhead_cost_per_hour: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub overhead_cost_per_hour: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_cost_per_hour: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_cost_per_hour: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cost_per_month: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost_per_month: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub overhead_cost_per_month: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub overhead_cost_per_month: Option<shared::Decimal>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub total_cost_per_month: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub total_cost_per_month: Option<shared::Decimal>,
     pub currency_name: String,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<CostPeriod, _>")]
+    pub extra: shared::Extra,
+}
+
+impl CostPeriod {
+    /// Returns the cost per hour with its currency.
+    pub fn cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the overhead cost per hour with its currency.
+    pub fn overhead_cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.overhead_cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the total cost per hour with its currency.
+    pub fn total_cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.total_cost_per_hour?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the cost per month with its currency.
+    pub fn cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the overhead cost per month with its currency.
+    pub fn overhead_cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.overhead_cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the total cost per month with its currency.
+    pub fn total_cost_per_month_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.total_cost_per_month?,
+            self.currency.clone(),
+        ))
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UtilizationTargetPeriod {
-    pub id: types::UUID,
+    pub id: types::Id<utilization::Utilization>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<types::Date>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<types::Date>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_percentage: Option<f64>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(
+        flatten,
+        deserialize_with = "shared::extra::<UtilizationTargetPeriod, _>"
+    )]
+    pub extra: shared::Extra,
 }
 
 // custom types: enum
@@ -138,6 +221,9 @@ pub enum InvitationStatus {
     NotInvited,
     Invited,
     Accepted,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -146,6 +232,20 @@ pub enum EmploymentType {
     Employee,
     Contractor,
     Other,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for EmploymentType {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -153,6 +253,9 @@ pub enum EmploymentType {
 pub enum AllowedTimeOffTypes {
     All,
     Custom,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -161,6 +264,9 @@ pub enum TimesheetCapacityPolicy {
     Unrestricted,
     Timesheet,
     Week,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -169,6 +275,9 @@ pub enum TimeOffApprovalMode {
     Auto,
     Manager,
     Member,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -177,6 +286,9 @@ pub enum InternalExpenseApprovalMode {
     Auto,
     Manager,
     Member,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
@@ -159,7 +159,8 @@ fn test_member_deserialize() {
     let member_deserialized =
         serde_json::from_str::<Member>(json_input).expect("member could not be deserialized");
     let member = Member {
-        id: types::UUID(String::from("3f3df320-dd95-4a42-8eae-99243fb2ea86")),
+        id: types::Id::try_from("3f3df320-dd95-4a42-8eae-99243fb2ea86")
+            .expect("uuid conversion failed"),
         name: String::from("Brian Lockett"),
         email: String::from("brian@example.com"),
         is_active: true,
@@ -168,8 +169,8 @@ fn test_member_deserialize() {
         invitation_status_id: InvitationStatus::Accepted,
         employment_type_id: EmploymentType::Employee,
         cost_method_id: Some(shared::CostMethod::FixedHourly),
-        default_rate: Some(100.0),
-        default_rate_currency: Some(String::from("USD")),
+        default_rate: Some(shared::Decimal::from(100)),
+        default_rate_currency: Some(shared::Currency::Usd),
         active_start_date: Some(types::Date(String::from("2020-08-03"))),
         active_end_date: Some(types::Date(String::from("2022-02-01"))),
         time_off_allowed: true,
@@ -182,118 +183,164 @@ fn test_member_deserialize() {
         timesheet_capacity_policy: TimesheetCapacityPolicy::Unrestricted,
         internal_id: Some(String::from("12345")),
         internal_notes: Some(String::from("Primary location: Atlanta, GA")),
-        created_at: types::Timestamp(String::from("2020-08-03T21:00:16.370Z")),
+        created_at: types::Timestamp::try_from("2020-08-03T21:00:16.370Z")
+            .expect("timestamp conversion failed"),
         track_time_by_duration: true,
         track_time_by_time_range: false,
         security_role: shared::Entity {
-            id: types::UUID(String::from("e31c9f7e-98cb-4ce7-81df-cc46bd9eb94f")),
+            id: types::Id::try_from("e31c9f7e-98cb-4ce7-81df-cc46bd9eb94f")
+                .expect("uuid conversion failed"),
             name: String::from("Workspace Admin"),
+            extra: shared::Extra::new(),
         },
         job_title: Some(shared::Entity {
-            id: types::UUID(String::from("5a2537bd-7fc6-4d68-b639-4ec79a7fda58")),
+            id: types::Id::try_from("5a2537bd-7fc6-4d68-b639-4ec79a7fda58")
+                .expect("uuid conversion failed"),
             name: String::from("HTML Developer"),
+            extra: shared::Extra::new(),
         }),
         level: Some(shared::Entity {
-            id: types::UUID(String::from("a1b2c3d4-e5f6-4789-ab01-234567890abc")),
+            id: types::Id::try_from("a1b2c3d4-e5f6-4789-ab01-234567890abc")
+                .expect("uuid conversion failed"),
             name: String::from("Senior"),
+            extra: shared::Extra::new(),
         }),
         discipline: Some(shared::Entity {
-            id: types::UUID(String::from("996bf40b-d856-4c8c-b14a-f41b3a015c5b")),
+            id: types::Id::try_from("996bf40b-d856-4c8c-b14a-f41b3a015c5b")
+                .expect("uuid conversion failed"),
             name: String::from("Software Engineering"),
+            extra: shared::Extra::new(),
         }),
         business_unit: Some(shared::Entity {
-            id: types::UUID(String::from("9d6f1c2a-3b8e-4f57-ad21-7e0c4f9b8d34")),
+            id: types::Id::try_from("9d6f1c2a-3b8e-4f57-ad21-7e0c4f9b8d34")
+                .expect("uuid conversion failed"),
             name: String::from("North America"),
+            extra: shared::Extra::new(),
         }),
         practice: Some(shared::Entity {
-            id: types::UUID(String::from("400a0c5c-32f1-41f5-a51d-e90807254965")),
+            id: types::Id::try_from("400a0c5c-32f1-41f5-a51d-e90807254965")
+                .expect("uuid conversion failed"),
             name: String::from("Retail"),
+            extra: shared::Extra::new(),
         }),
         location: Some(shared::Entity {
-            id: types::UUID(String::from("75e6ed16-2cdb-466e-88ad-ceeb0663ddb2")),
+            id: types::Id::try_from("75e6ed16-2cdb-466e-88ad-ceeb0663ddb2")
+                .expect("uuid conversion failed"),
             name: String::from("North America"),
+            extra: shared::Extra::new(),
         }),
         manager: Some(shared::Entity {
-            id: types::UUID(String::from("5de6d319-ae80-4484-8b3d-0a9ff9580292")),
+            id: types::Id::try_from("5de6d319-ae80-4484-8b3d-0a9ff9580292")
+                .expect("uuid conversion failed"),
             name: String::from("Steven Rodriguez"),
+            extra: shared::Extra::new(),
         }),
         time_off_approver: Some(shared::Entity {
-            id: types::UUID(String::from("b6816355-8945-40aa-b798-b0d6fd89e437")),
+            id: types::Id::try_from("b6816355-8945-40aa-b798-b0d6fd89e437")
+                .expect("uuid conversion failed"),
             name: String::from("Edna Blumer"),
+            extra: shared::Extra::new(),
         }),
         internal_expense_approver: Some(shared::Entity {
-            id: types::UUID(String::from("82866d0d-ab62-43cd-92b8-a30ecc78bd89")),
+            id: types::Id::try_from("82866d0d-ab62-43cd-92b8-a30ecc78bd89")
+                .expect("uuid conversion failed"),
             name: String::from("Priya Patel"),
+            extra: shared::Extra::new(),
         }),
         holiday_schedule: Some(shared::Entity {
-            id: types::UUID(String::from("321c155b-147e-4bd2-8aab-b9c3a3afeab1")),
+            id: types::Id::try_from("321c155b-147e-4bd2-8aab-b9c3a3afeab1")
+                .expect("uuid conversion failed"),
             name: String::from("U.S. Holidays"),
+            extra: shared::Extra::new(),
         }),
         tags: vec![
             shared::Entity {
-                id: types::UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")),
+                id: types::Id::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
+                    .expect("uuid conversion failed"),
                 name: String::from("Atlanta Office"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("5533897a-450f-42b0-a419-aa3142dd9aad")),
+                id: types::Id::try_from("5533897a-450f-42b0-a419-aa3142dd9aad")
+                    .expect("uuid conversion failed"),
                 name: String::from("Buenos Aires Office"),
+                extra: shared::Extra::new(),
             },
         ],
         certifications: vec![shared::Entity {
-            id: types::UUID(String::from("d0e1f2a3-b4c5-4678-34ab-123456789d45")),
+            id: types::Id::try_from("d0e1f2a3-b4c5-4678-34ab-123456789d45")
+                .expect("uuid conversion failed"),
             name: String::from("AWS Certified Solutions Architect"),
+            extra: shared::Extra::new(),
         }],
         skills: vec![
             shared::Entity {
-                id: types::UUID(String::from("802c3214-59b4-4f9b-9eb1-4da2674becc3")),
+                id: types::Id::try_from("802c3214-59b4-4f9b-9eb1-4da2674becc3")
+                    .expect("uuid conversion failed"),
                 name: String::from("JavaScript"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("efae4ed9-6967-4f76-9946-7b9b1008fe5e")),
+                id: types::Id::try_from("efae4ed9-6967-4f76-9946-7b9b1008fe5e")
+                    .expect("uuid conversion failed"),
                 name: String::from("HTML"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("828f3a35-1317-49fb-acad-419a62f74d44")),
+                id: types::Id::try_from("828f3a35-1317-49fb-acad-419a62f74d44")
+                    .expect("uuid conversion failed"),
                 name: String::from("CSS"),
+                extra: shared::Extra::new(),
             },
         ],
         time_off_types: vec![
             shared::Entity {
-                id: types::UUID(String::from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")),
+                id: types::Id::try_from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")
+                    .expect("uuid conversion failed"),
                 name: String::from("Holiday"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("e663875e-5c11-4928-b194-66f2174740b7")),
+                id: types::Id::try_from("e663875e-5c11-4928-b194-66f2174740b7")
+                    .expect("uuid conversion failed"),
                 name: String::from("Other Leave"),
+                extra: shared::Extra::new(),
             },
         ],
         availability_periods: vec![AvailabilityPeriod {
-            id: types::UUID(String::from("6675130f-d975-45ab-9971-4405062a9e92")),
+            id: types::UUID::try_from("6675130f-d975-45ab-9971-4405062a9e92")
+                .expect("uuid conversion failed"),
             start: Some(types::Date(String::from("2020-08-03"))),
             end: Some(types::Date(String::from("2022-02-01"))),
             hours_per_day: vec![8, 8, 8, 8, 8, 0, 0],
+            extra: shared::Extra::new(),
         }],
         cost_periods: vec![CostPeriod {
-            id: types::UUID(String::from("ae7268fd-2836-4437-b592-86289333e205")),
+            id: types::Id::try_from("ae7268fd-2836-4437-b592-86289333e205")
+                .expect("uuid conversion failed"),
             start: Some(types::Date(String::from("2020-08-03"))),
             end: Some(types::Date(String::from("2022-02-01"))),
-            currency: String::from("USD"),
+            currency: shared::Currency::Usd,
             cost_method_id: shared::CostMethod::FixedHourly,
-            cost_per_hour: Some(65.0),
-            overhead_cost_per_hour: Some(20.0),
-            total_cost_per_hour: Some(85.0),
+            cost_per_hour: Some(shared::Decimal::from(65)),
+            overhead_cost_per_hour: Some(shared::Decimal::from(20)),
+            total_cost_per_hour: Some(shared::Decimal::from(85)),
             cost_per_month: None,
             overhead_cost_per_month: None,
             total_cost_per_month: None,
             currency_name: String::from("US Dollar"),
+            extra: shared::Extra::new(),
         }],
         utilization_target_periods: Some(vec![UtilizationTargetPeriod {
-            id: types::UUID(String::from("f77df409-f68b-4a98-a6c6-0fa97d523e20")),
+            id: types::Id::try_from("f77df409-f68b-4a98-a6c6-0fa97d523e20")
+                .expect("uuid conversion failed"),
             start: Some(types::Date(String::from("2020-08-03"))),
             end: Some(types::Date(String::from("2022-02-01"))),
             target_percentage: Some(80.0),
+            extra: shared::Extra::new(),
         }]),
         forbid_timesheet_submission_when_below_capacity: Some(false),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         member_deserialized, member,
@@ -316,3 +363,8 @@ fn test_member_deserialize() {
         "members did not contain the expected values"
     );
 }
+
+#[test]
+fn test_employment_type_display() {
+    assert_eq!(EmploymentType::Contractor.to_string(), "contractor")
+}
@@ -2,7 +2,7 @@
 //!
 //! `model::project` is a model for the Ruddr Project object. This module is not publically accessible, but the structs and members are public for reading from `interface::project` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/projects/get-a-project.md)
-use crate::model::{shared, types};
+use crate::model::{client, member, shared, types};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -18,7 +18,7 @@ pub struct Projects {
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
-    pub id: types::UUID,
+    pub id: types::Id<Project>,
     pub key: types::Slug,
     pub name: String,
     pub notes: Option<String>,
@@ -29,10 +29,12 @@ pub struct Project {
     pub po_number: Option<String>,
     pub billing_type_id: BillingType,
     pub is_billable: bool,
-    pub currency: String,
+    pub currency: shared::Currency,
     pub revenue_recognition_method: Option<RevenueRecognitionMethod>,
-    pub fixed_fee: Option<i64>,
-    pub fixed_recurring_fee: Option<i64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub fixed_fee: Option<shared::Decimal>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub fixed_recurring_fee: Option<shared::Decimal>,
     pub fixed_recurring_start: Option<types::Date>,
     pub fixed_recurring_end: Option<types::Date>,
     pub use_roles: bool,
@@ -55,29 +57,56 @@ pub struct Project {
     pub cloud_folder_url: Option<String>,
     pub created_at: types::Timestamp,
     pub completed_on: Option<types::Date>,
-    pub client: shared::Entity,
+    pub client: shared::Entity<client::Client>,
     pub practice: Option<shared::Entity>,
     pub project_type: Option<shared::Entity>,
     pub tags: Vec<shared::Entity>,
-    pub sales_representative: Option<shared::Entity>,
+    pub sales_representative: Option<shared::Entity<member::Member>>,
     pub business_unit: Option<shared::Entity>,
     pub project_group: Option<shared::Entity>,
     pub budget: Option<Budget>,
     pub monthly_budget: Option<Budget>,
     pub integrations: Vec<Integration>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Project, _>")]
+    pub extra: shared::Extra,
+}
+
+impl Project {
+    /// Returns the fixed fee with its currency.
+    pub fn fixed_fee_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(self.fixed_fee?, self.currency.clone()))
+    }
+
+    /// Returns the fixed recurring fee with its currency.
+    pub fn fixed_recurring_fee_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.fixed_recurring_fee?,
+            self.currency.clone(),
+        ))
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Budget {
-    pub revenue: i64,
-    pub services_revenue: i64,
-    pub product_revenue: i64,
-    pub other_revenue: i64,
-    pub billable_expenses: i64,
-    pub non_billable_expenses: i64,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub revenue: shared::Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub services_revenue: shared::Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub product_revenue: shared::Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub other_revenue: shared::Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub billable_expenses: shared::Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub non_billable_expenses: shared::Decimal,
     pub billable_hours: i64,
     pub non_billable_hours: i64,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Budget, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -87,6 +116,226 @@ pub struct Integration {
     pub integration_type: IntegrationType,
     pub connection_id: types::UUID,
     pub external_id: String,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Integration, _>")]
+    pub extra: shared::Extra,
+}
+
+/// Model for Project input used with Create and Update operations.
+/// Unspecified members are omitted from the request body so that Update operations only modify the specified members.
+/// ```ignore
+/// let project_input = ProjectInput {
+///     name: Some(String::from("Vendor Portal")),
+///     client_id: Some(types::UUID::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22").expect("invalid UUID")),
+///     billing_type_id: Some(BillingType::Fixed),
+///     fixed_fee: Some(shared::Decimal::new(50000, 0)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<types::Id<client::Client>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<types::Slug>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_id: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<types::Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<types::Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub po_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_type_id: Option<BillingType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_billable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<shared::Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revenue_recognition_method: Option<RevenueRecognitionMethod>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "rust_decimal::serde::float_option"
+    )]
+    pub fixed_fee: Option<shared::Decimal>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "rust_decimal::serde::float_option"
+    )]
+    pub fixed_recurring_fee: Option<shared::Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_recurring_start: Option<types::Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_recurring_end: Option<types::Date>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_roles: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_budget: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_mode: Option<BudgetMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget: Option<Budget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_monthly_budget: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_budget_mode: Option<MonthlyBudgetMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_budget: Option<Budget>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_max_member_hours_per_day: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_member_hours_per_day: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_max_member_hours_per_week: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_member_hours_per_week: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cap_max_member_hours_per_month: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_member_hours_per_month: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_tasks: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_status_id: Option<shared::RecordStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_productive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_time_and_expenses: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_time_to_assigned_roles: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud_folder_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub practice_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_type_id: Option<types::UUID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_ids: Option<Vec<types::UUID>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sales_representative_id: Option<types::Id<member::Member>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_unit_id: Option<types::UUID>,
+}
+
+impl ProjectInput {
+    /// Validates the input for a Create operation, which additionally requires the members the Ruddr API requires for a new Project, and the members dependent on the specified billing type, budgets, and member hours caps.
+    pub fn validate_create(&self) -> Result<(), shared::ValidationError> {
+        self.validate()?;
+        self.validate_dependents()?;
+        if self.name.is_none() {
+            return Err(shared::ValidationError(String::from(
+                "name is required to create a project",
+            )));
+        }
+        if self.client_id.is_none() {
+            return Err(shared::ValidationError(String::from(
+                "client_id is required to create a project",
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validates the input for an Update operation, which only validates the specified members as the existing Project may already have their dependent members.
+    pub fn validate(&self) -> Result<(), shared::ValidationError> {
+        if let Some(name) = &self.name
+            && name.trim().is_empty()
+        {
+            return Err(shared::ValidationError(String::from(
+                "name must not be empty",
+            )));
+        }
+        if let (Some(start), Some(end)) = (&self.start, &self.end)
+            && start > end
+        {
+            return Err(shared::ValidationError(format!(
+                "start {start} must not be after end {end}"
+            )));
+        }
+        for (period, max) in [
+            ("day", self.max_member_hours_per_day),
+            ("week", self.max_member_hours_per_week),
+            ("month", self.max_member_hours_per_month),
+        ] {
+            if let Some(max) = max
+                && max <= 0
+            {
+                return Err(shared::ValidationError(format!(
+                    "max_member_hours_per_{period} must be positive"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // validate the members dependent on other specified members for a new project
+    fn validate_dependents(&self) -> Result<(), shared::ValidationError> {
+        // billing type dependent members
+        match self.billing_type_id {
+            Some(BillingType::Fixed) if self.fixed_fee.is_none() => {
+                return Err(shared::ValidationError(String::from(
+                    "fixed_fee is required for fixed billing type",
+                )));
+            }
+            Some(BillingType::FixedRecurring)
+                if self.fixed_recurring_fee.is_none() || self.fixed_recurring_start.is_none() =>
+            {
+                return Err(shared::ValidationError(String::from(
+                    "fixed_recurring_fee and fixed_recurring_start are required for fixed recurring billing type",
+                )));
+            }
+            _ => {}
+        }
+
+        // budget dependent members
+        if self.use_budget == Some(true) && self.budget_mode.is_none() {
+            return Err(shared::ValidationError(String::from(
+                "budget_mode is required when use_budget is enabled",
+            )));
+        }
+        if self.use_monthly_budget == Some(true) && self.monthly_budget_mode.is_none() {
+            return Err(shared::ValidationError(String::from(
+                "monthly_budget_mode is required when use_monthly_budget is enabled",
+            )));
+        }
+
+        // member hours caps dependent members
+        for (period, cap, max) in [
+            (
+                "day",
+                self.cap_max_member_hours_per_day,
+                self.max_member_hours_per_day,
+            ),
+            (
+                "week",
+                self.cap_max_member_hours_per_week,
+                self.max_member_hours_per_week,
+            ),
+            (
+                "month",
+                self.cap_max_member_hours_per_month,
+                self.max_member_hours_per_month,
+            ),
+        ] {
+            if cap == Some(true) && max.is_none() {
+                return Err(shared::ValidationError(format!(
+                    "max_member_hours_per_{period} is required when cap_max_member_hours_per_{period} is enabled"
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 // custom types: enum
@@ -99,6 +348,9 @@ pub enum Status {
     Paused,
     Completed,
     Cancelled,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl fmt::Display for Status {
@@ -120,6 +372,20 @@ pub enum BillingType {
     Fixed,
     FixedRecurring,
     NonBillable,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for BillingType {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -127,6 +393,9 @@ pub enum BillingType {
 pub enum RevenueRecognitionMethod {
     Invoiced,
     Manual,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -135,6 +404,9 @@ pub enum BudgetMode {
     Summary,
     Detailed,
     Aggregated,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -142,12 +414,18 @@ pub enum BudgetMode {
 pub enum MonthlyBudgetMode {
     Summary,
     Detailed,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum IntegrationType {
     Qbo,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[cfg(test)]
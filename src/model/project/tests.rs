@@ -97,7 +97,8 @@ fn test_project_deserialize() {
     let project_deserialized =
         serde_json::from_str::<Project>(json_input).expect("time entry could not be deserialized");
     let project = Project {
-        id: types::UUID(String::from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")),
+        id: types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+            .expect("uuid conversion failed"),
         key: types::Slug(String::from("vendor-portal")),
         name: String::from("Vendor Portal"),
         notes: Some(String::from(
@@ -110,7 +111,7 @@ fn test_project_deserialize() {
         po_number: None,
         billing_type_id: BillingType::FixedRecurring,
         is_billable: true,
-        currency: String::from("USD"),
+        currency: shared::Currency::Usd,
         revenue_recognition_method: Some(RevenueRecognitionMethod::Manual),
         fixed_fee: None,
         fixed_recurring_fee: None,
@@ -134,58 +135,74 @@ fn test_project_deserialize() {
         lock_time_and_expenses: false,
         track_time_to_assigned_roles: None,
         cloud_folder_url: None,
-        created_at: types::Timestamp(String::from("2022-03-15T14:59:18.825Z")),
+        created_at: types::Timestamp::try_from("2022-03-15T14:59:18.825Z")
+            .expect("timestamp conversion failed"),
         completed_on: Some(types::Date(String::from("2025-10-28"))),
         client: shared::Entity {
-            id: types::UUID(String::from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")),
+            id: types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                .expect("uuid conversion failed"),
             name: String::from("Acme Company"),
+            extra: shared::Extra::new(),
         },
         practice: None,
         project_type: None,
         tags: vec![
             shared::Entity {
-                id: types::UUID(String::from("626db436-98bf-40cb-9937-c382af5d818c")),
+                id: types::Id::try_from("626db436-98bf-40cb-9937-c382af5d818c")
+                    .expect("uuid conversion failed"),
                 name: String::from("Atlanta Office"),
+                extra: shared::Extra::new(),
             },
             shared::Entity {
-                id: types::UUID(String::from("9f26fb15-23f1-49a6-8558-c19ad4338472")),
+                id: types::Id::try_from("9f26fb15-23f1-49a6-8558-c19ad4338472")
+                    .expect("uuid conversion failed"),
                 name: String::from("Data Analytics"),
+                extra: shared::Extra::new(),
             },
         ],
         sales_representative: Some(shared::Entity {
-            id: types::UUID(String::from("a6816355-8945-40aa-b798-b0d6fd89e437")),
+            id: types::Id::try_from("a6816355-8945-40aa-b798-b0d6fd89e437")
+                .expect("uuid conversion failed"),
             name: String::from("Joe"),
+            extra: shared::Extra::new(),
         }),
         business_unit: Some(shared::Entity {
-            id: types::UUID(String::from("0e8351ea-6b3c-4307-97cc-196448de0ef1")),
+            id: types::Id::try_from("0e8351ea-6b3c-4307-97cc-196448de0ef1")
+                .expect("uuid conversion failed"),
             name: String::from("Palermo North America"),
+            extra: shared::Extra::new(),
         }),
         project_group: None,
         budget: Some(Budget {
-            revenue: 602500,
-            services_revenue: 600000,
-            product_revenue: 15000,
-            other_revenue: 2000,
-            billable_expenses: 500,
-            non_billable_expenses: 150,
+            revenue: shared::Decimal::from(602500),
+            services_revenue: shared::Decimal::from(600000),
+            product_revenue: shared::Decimal::from(15000),
+            other_revenue: shared::Decimal::from(2000),
+            billable_expenses: shared::Decimal::from(500),
+            non_billable_expenses: shared::Decimal::from(150),
             billable_hours: 150,
             non_billable_hours: 10,
+            extra: shared::Extra::new(),
         }),
         monthly_budget: Some(Budget {
-            revenue: 51220,
-            services_revenue: 50000,
-            product_revenue: 2500,
-            other_revenue: 920,
-            billable_expenses: 300,
-            non_billable_expenses: 50,
+            revenue: shared::Decimal::from(51220),
+            services_revenue: shared::Decimal::from(50000),
+            product_revenue: shared::Decimal::from(2500),
+            other_revenue: shared::Decimal::from(920),
+            billable_expenses: shared::Decimal::from(300),
+            non_billable_expenses: shared::Decimal::from(50),
             billable_hours: 10,
             non_billable_hours: 2,
+            extra: shared::Extra::new(),
         }),
         integrations: vec![Integration {
             integration_type: IntegrationType::Qbo,
-            connection_id: types::UUID(String::from("c4a7e2f9-83b1-4d65-9e07-2f1b6a4c80d3")),
+            connection_id: types::UUID::try_from("c4a7e2f9-83b1-4d65-9e07-2f1b6a4c80d3")
+                .expect("uuid conversion failed"),
             external_id: String::from("456"),
+            extra: shared::Extra::new(),
         }],
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         project_deserialized, project,
@@ -213,3 +230,172 @@ fn test_project_deserialize() {
 fn test_status_display() {
     assert_eq!(Status::InProgress.to_string(), "in_progress")
 }
+
+#[test]
+fn test_billing_type_display() {
+    assert_eq!(BillingType::TimeAndMaterials.to_string(), "tm");
+    assert_eq!(BillingType::FixedRecurring.to_string(), "fixed_recurring")
+}
+
+#[test]
+fn test_project_input_serialize() {
+    let project_input = ProjectInput {
+        name: Some(String::from("Vendor Portal")),
+        client_id: Some(
+            types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                .expect("uuid conversion failed"),
+        ),
+        billing_type_id: Some(BillingType::TimeAndMaterials),
+        fixed_fee: Some(shared::Decimal::new(500005, 1)),
+        cap_max_member_hours_per_week: Some(true),
+        max_member_hours_per_week: Some(40),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_string(&project_input).expect("project input could not be serialized"),
+        r#"{"name":"Vendor Portal","clientId":"d5afaffe-09e5-4d73-b02c-905b40fc6c22","billingTypeId":"tm","fixedFee":50000.5,"capMaxMemberHoursPerWeek":true,"maxMemberHoursPerWeek":40}"#,
+        "project input did not serialize to the expected body"
+    );
+}
+
+#[test]
+fn test_project_input_validate() {
+    assert_eq!(
+        ProjectInput {
+            client_id: Some(
+                types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                    .expect("uuid conversion failed")
+            ),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from("name is required to create a project"))
+    );
+    assert_eq!(
+        ProjectInput {
+            name: Some(String::from("Vendor Portal")),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from("client_id is required to create a project"))
+    );
+    assert_eq!(
+        ProjectInput {
+            start: Some(types::Date(String::from("2022-01-31"))),
+            end: Some(types::Date(String::from("2021-09-01"))),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err(),
+        shared::ValidationError(String::from(
+            "start 2022-01-31 must not be after end 2021-09-01"
+        ))
+    );
+    assert_eq!(
+        ProjectInput {
+            billing_type_id: Some(BillingType::Fixed),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from("fixed_fee is required for fixed billing type"))
+    );
+    assert_eq!(
+        ProjectInput {
+            billing_type_id: Some(BillingType::FixedRecurring),
+            fixed_recurring_fee: Some(shared::Decimal::from(1000)),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from(
+            "fixed_recurring_fee and fixed_recurring_start are required for fixed recurring billing type"
+        ))
+    );
+    assert_eq!(
+        ProjectInput {
+            use_budget: Some(true),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from(
+            "budget_mode is required when use_budget is enabled"
+        ))
+    );
+    assert_eq!(
+        ProjectInput {
+            cap_max_member_hours_per_day: Some(true),
+            ..Default::default()
+        }
+        .validate_create()
+        .unwrap_err(),
+        shared::ValidationError(String::from(
+            "max_member_hours_per_day is required when cap_max_member_hours_per_day is enabled"
+        ))
+    );
+    assert_eq!(
+        ProjectInput {
+            max_member_hours_per_month: Some(0),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err(),
+        shared::ValidationError(String::from("max_member_hours_per_month must be positive"))
+    );
+    assert!(
+        ProjectInput {
+            name: Some(String::from("Vendor Portal")),
+            client_id: Some(
+                types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                    .expect("uuid conversion failed")
+            ),
+            billing_type_id: Some(BillingType::Fixed),
+            fixed_fee: Some(shared::Decimal::from(50000)),
+            ..Default::default()
+        }
+        .validate_create()
+        .is_ok()
+    );
+    // partial updates where the existing project may already have the dependent members
+    for input in [
+        ProjectInput {
+            billing_type_id: Some(BillingType::Fixed),
+            ..Default::default()
+        },
+        ProjectInput {
+            use_budget: Some(true),
+            ..Default::default()
+        },
+        ProjectInput {
+            cap_max_member_hours_per_day: Some(true),
+            ..Default::default()
+        },
+    ] {
+        assert!(input.validate().is_ok());
+    }
+}
+
+#[test]
+fn test_unknown_variants() {
+    let status =
+        serde_json::from_str::<Status>("\"on_hold\"").expect("status could not be deserialized");
+    assert_eq!(status, Status::Unknown(String::from("on_hold")));
+    assert_eq!(status.to_string(), "on_hold");
+    assert_eq!(
+        serde_json::to_string(&status).expect("status could not be serialized"),
+        "\"on_hold\""
+    );
+    assert_eq!(
+        serde_json::from_str::<BillingType>("\"tm\"")
+            .expect("billing type could not be deserialized"),
+        BillingType::TimeAndMaterials
+    );
+    assert_eq!(
+        serde_json::from_str::<IntegrationType>("\"xero\"")
+            .expect("integration type could not be deserialized"),
+        IntegrationType::Unknown(String::from("xero"))
+    )
+}
@@ -17,11 +17,12 @@ pub struct Roles {
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Role {
-    pub id: types::UUID,
+    pub id: types::Id<Role>,
     pub name: String,
     pub is_active: bool,
     pub is_billable: bool,
-    pub rate: Option<f64>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub rate: Option<shared::Decimal>,
     pub created_at: types::Timestamp,
     pub project: shared::Project,
     pub discipline: Option<shared::Entity>,
@@ -29,6 +30,9 @@ pub struct Role {
     pub location: Option<shared::Entity>,
     pub budget: Option<Budget>,
     pub monthly_budget: Option<Budget>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Role, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -36,6 +40,9 @@ pub struct Role {
 pub struct Budget {
     pub billable_hours: Option<i64>,
     pub non_billable_hours: Option<i64>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Budget, _>")]
+    pub extra: shared::Extra,
 }
 
 #[cfg(test)]
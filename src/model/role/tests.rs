@@ -39,37 +39,50 @@ fn test_role_deserialize() {
     let role_deserialized =
         serde_json::from_str::<Role>(json_input).expect("role could not be deserialized");
     let role = Role {
-        id: types::UUID(String::from("7ad5a34a-07b7-48e9-a760-bd220d52e354")),
+        id: types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354")
+            .expect("uuid conversion failed"),
         name: String::from("Project Manager"),
         is_active: true,
         is_billable: true,
-        rate: Some(125.0),
-        created_at: types::Timestamp(String::from("2022-03-15T15:00:08.626Z")),
+        rate: Some(shared::Decimal::from(125)),
+        created_at: types::Timestamp::try_from("2022-03-15T15:00:08.626Z")
+            .expect("timestamp conversion failed"),
         project: shared::Project {
-            id: types::UUID(String::from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")),
+            id: types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                .expect("uuid conversion failed"),
             name: String::from("Vendor Portal"),
             client: shared::Entity {
-                id: types::UUID(String::from("4cacdf11-71d1-4fbb-90ee-b091803581b0")),
+                id: types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+                    .expect("uuid conversion failed"),
                 name: String::from("Joe's Shop"),
+                extra: shared::Extra::new(),
             },
+            extra: shared::Extra::new(),
         },
         discipline: None,
         practice: Some(shared::Entity {
-            id: types::UUID(String::from("14aa2bab-ea87-4ca3-9d5b-08aade12376e")),
+            id: types::Id::try_from("14aa2bab-ea87-4ca3-9d5b-08aade12376e")
+                .expect("uuid conversion failed"),
             name: String::from("Project Management"),
+            extra: shared::Extra::new(),
         }),
         location: Some(shared::Entity {
-            id: types::UUID(String::from("6f52a290-7b61-40d0-b07a-c1bf4f7a14df")),
+            id: types::Id::try_from("6f52a290-7b61-40d0-b07a-c1bf4f7a14df")
+                .expect("uuid conversion failed"),
             name: String::from("Remote"),
+            extra: shared::Extra::new(),
         }),
         budget: Some(Budget {
             billable_hours: None,
             non_billable_hours: None,
+            extra: shared::Extra::new(),
         }),
         monthly_budget: Some(Budget {
             billable_hours: Some(30),
             non_billable_hours: Some(2),
+            extra: shared::Extra::new(),
         }),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         role, role_deserialized,
@@ -1,33 +1,308 @@
 //! # Shared
 //!
 //! This module contains model structs and enums shared across the Ruddr API objects.
-use crate::model::types;
-use serde::{Deserialize, Serialize};
+use crate::model::{client, project, types};
+/// Decimal type of monetary members, which are deserialized through `f64` and are therefore exact up to 15 significant digits.
+pub use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Mutex;
 
 // structs
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Project {
-    pub id: types::UUID,
+    pub id: types::Id<project::Project>,
     pub name: String,
-    pub client: Entity,
+    pub client: Entity<client::Client>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "extra::<Project, _>")]
+    pub extra: Extra,
 }
 
 // Simple generic entity struct for models comprised of only an ID and a name.
+// The resource type parameter of the ID defaults to the unit type for resources not modeled by this crate.
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub struct Entity {
-    pub id: types::UUID,
+#[serde(rename_all = "camelCase", bound = "")]
+pub struct Entity<Resource = ()> {
+    pub id: types::Id<Resource>,
     pub name: String,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "extra::<Entity<Resource>, _>")]
+    pub extra: Extra,
+}
+
+/// Monetary amount with its currency for exact decimal arithmetic on rates, costs, and amounts.
+/// Arithmetic between amounts is only defined for the same currency.
+/// The Ruddr API returns amounts as JSON numbers which are parsed through `f64`, so deserialized amounts are exact up to 15 significant digits e.g. `9999999999999.99`, and amounts beyond the `Decimal` range of about `7.9e28` fail to deserialize.
+/// Models retain the amounts and currency as separate members in the shape returned by the Ruddr API, where one currency applies to several amounts, and expose each amount as `Money` through an accessor e.g. `project::Project::fixed_fee_money`.
+/// ```ignore
+/// let fee = Money::new(Decimal::new(50000, 0), Currency::Usd);
+/// let total = fee.checked_add(&Money::new(Decimal::new(1250, 2), Currency::Usd)); // Some(50012.50 USD)
+/// ```
+#[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Debug, Clone)]
+pub struct Money {
+    #[serde(with = "rust_decimal::serde::float")]
+    pub amount: Decimal,
+    pub currency: Currency,
+}
+
+impl Money {
+    /// Instantiates the amount in the currency.
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Money { amount, currency }
+    }
+
+    /// Returns the sum of the amounts, or `None` if the currencies differ or the sum overflows.
+    pub fn checked_add(&self, other: &Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        Some(Money::new(
+            self.amount.checked_add(other.amount)?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the difference of the amounts, or `None` if the currencies differ or the difference overflows.
+    pub fn checked_sub(&self, other: &Money) -> Option<Money> {
+        if self.currency != other.currency {
+            return None;
+        }
+        Some(Money::new(
+            self.amount.checked_sub(other.amount)?,
+            self.currency.clone(),
+        ))
+    }
+
+    /// Returns the amount multiplied by the factor e.g. a rate multiplied by hours, or `None` if the product overflows.
+    pub fn checked_mul(&self, factor: Decimal) -> Option<Money> {
+        Some(Money::new(
+            self.amount.checked_mul(factor)?,
+            self.currency.clone(),
+        ))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(format, "{} {}", self.amount, self.currency)
+    }
 }
 
 // enums
+/// ISO 4217 currency codes.
+#[derive(PartialEq, Eq, Hash, Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    Aed,
+    Afn,
+    All,
+    Amd,
+    Ang,
+    Aoa,
+    Ars,
+    Aud,
+    Awg,
+    Azn,
+    Bam,
+    Bbd,
+    Bdt,
+    Bgn,
+    Bhd,
+    Bif,
+    Bmd,
+    Bnd,
+    Bob,
+    Bov,
+    Brl,
+    Bsd,
+    Btn,
+    Bwp,
+    Byn,
+    Bzd,
+    Cad,
+    Cdf,
+    Che,
+    Chf,
+    Chw,
+    Clf,
+    Clp,
+    Cny,
+    Cop,
+    Cou,
+    Crc,
+    Cuc,
+    Cup,
+    Cve,
+    Czk,
+    Djf,
+    Dkk,
+    Dop,
+    Dzd,
+    Egp,
+    Ern,
+    Etb,
+    Eur,
+    Fjd,
+    Fkp,
+    Gbp,
+    Gel,
+    Ghs,
+    Gip,
+    Gmd,
+    Gnf,
+    Gtq,
+    Gyd,
+    Hkd,
+    Hnl,
+    Htg,
+    Huf,
+    Idr,
+    Ils,
+    Inr,
+    Iqd,
+    Irr,
+    Isk,
+    Jmd,
+    Jod,
+    Jpy,
+    Kes,
+    Kgs,
+    Khr,
+    Kmf,
+    Kpw,
+    Krw,
+    Kwd,
+    Kyd,
+    Kzt,
+    Lak,
+    Lbp,
+    Lkr,
+    Lrd,
+    Lsl,
+    Lyd,
+    Mad,
+    Mdl,
+    Mga,
+    Mkd,
+    Mmk,
+    Mnt,
+    Mop,
+    Mru,
+    Mur,
+    Mvr,
+    Mwk,
+    Mxn,
+    Mxv,
+    Myr,
+    Mzn,
+    Nad,
+    Ngn,
+    Nio,
+    Nok,
+    Npr,
+    Nzd,
+    Omr,
+    Pab,
+    Pen,
+    Pgk,
+    Php,
+    Pkr,
+    Pln,
+    Pyg,
+    Qar,
+    Ron,
+    Rsd,
+    Rub,
+    Rwf,
+    Sar,
+    Sbd,
+    Scr,
+    Sdg,
+    Sek,
+    Sgd,
+    Shp,
+    Sle,
+    Sll,
+    Sos,
+    Srd,
+    Ssp,
+    Stn,
+    Svc,
+    Syp,
+    Szl,
+    Thb,
+    Tjs,
+    Tmt,
+    Tnd,
+    Top,
+    Try,
+    Ttd,
+    Twd,
+    Tzs,
+    Uah,
+    Ugx,
+    Usd,
+    Usn,
+    Uyi,
+    Uyu,
+    Uyw,
+    Uzs,
+    Ved,
+    Ves,
+    Vnd,
+    Vuv,
+    Wst,
+    Xaf,
+    Xag,
+    Xau,
+    Xba,
+    Xbb,
+    Xbc,
+    Xbd,
+    Xcd,
+    Xcg,
+    Xdr,
+    Xof,
+    Xpd,
+    Xpf,
+    Xpt,
+    Xsu,
+    Xts,
+    Xua,
+    Xxx,
+    Yer,
+    Zar,
+    Zmw,
+    Zwg,
+    Zwl,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic upper case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
+}
+
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CostMethod {
     Hourly,
     FixedHourly,
     FixedMonthly,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -35,6 +310,20 @@ pub enum CostMethod {
 pub enum RecordStatus {
     Active,
     Archived,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for RecordStatus {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -44,6 +333,55 @@ pub enum Status {
     PendingApproval,
     Approved,
     Rejected,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
+}
+
+// errors
+/// Error type for client-side validation of input models used with Create and Update operations.
+#[derive(Debug, PartialEq)]
+pub struct ValidationError(pub(crate) String);
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(format, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+// unrecognized fields
+/// Fields returned by the Ruddr API which are not yet modeled by this crate, keyed by their JSON name.
+pub type Extra = serde_json::Map<String, serde_json::Value>;
+
+// models with unrecognized fields already warned about, so that list operations do not warn for every result
+static EXTRA_WARNED: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+// deserializer for the flattened unrecognized fields of a model which logs their names to surface Ruddr API changes
+pub(crate) fn extra<'de, Model, D: Deserializer<'de>>(deserializer: D) -> Result<Extra, D::Error> {
+    let extra = Extra::deserialize(deserializer)?;
+    if !extra.is_empty() {
+        let model = std::any::type_name::<Model>();
+        let fields = extra.keys().map(String::as_str).collect::<Vec<&str>>();
+        if EXTRA_WARNED.lock().unwrap().insert(model) {
+            log::warn!("{model} contains fields unrecognized by this crate: {fields:?}");
+        } else {
+            log::debug!("{model} contains fields unrecognized by this crate: {fields:?}");
+        }
+    }
+    Ok(extra)
 }
 
 #[cfg(test)]
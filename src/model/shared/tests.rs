@@ -14,12 +14,16 @@ fn test_project_deserialize() {
     let project_deserialized =
         serde_json::from_str::<Project>(json_input).expect("project could not be deserialized");
     let project = Project {
-        id: types::UUID(String::from("4497fa99-27a4-4509-9748-83e4399296e3")),
+        id: types::Id::try_from("4497fa99-27a4-4509-9748-83e4399296e3")
+            .expect("uuid conversion failed"),
         name: String::from("Vendor Portal"),
         client: Entity {
-            id: types::UUID(String::from("4cacdf11-71d1-4fbb-90ee-b091803581b0")),
+            id: types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+                .expect("uuid conversion failed"),
             name: String::from("Joe's Shop"),
+            extra: Extra::new(),
         },
+        extra: Extra::new(),
     };
     assert_eq!(project, project_deserialized);
 }
@@ -34,8 +38,127 @@ fn test_entity_deserialize() {
     let entity_deserialized =
         serde_json::from_str::<Entity>(json_input).expect("entity could not be deserialized");
     let entity = Entity {
-        id: types::UUID(String::from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")),
+        id: types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+            .expect("uuid conversion failed"),
         name: String::from("John Smith"),
+        extra: Extra::new(),
     };
     assert_eq!(entity, entity_deserialized);
 }
+
+#[test]
+fn test_entity_extra() {
+    let json_input = r#"{"id":"4497fa99-27a4-4509-9748-83e4399296e3","name":"Vendor Portal","client":{"id":"4cacdf11-71d1-4fbb-90ee-b091803581b0","name":"Joe's Shop","isInternal":false},"code":"VP"}"#;
+    let project =
+        serde_json::from_str::<Project>(json_input).expect("project could not be deserialized");
+    assert_eq!(project.extra["code"], serde_json::Value::from("VP"));
+    assert_eq!(
+        project.client.extra["isInternal"],
+        serde_json::Value::Bool(false)
+    );
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &serde_json::to_string(&project).expect("project could not be serialized")
+        )
+        .expect("project could not be reparsed"),
+        serde_json::from_str::<serde_json::Value>(json_input).expect("input could not be parsed")
+    );
+}
+
+#[test]
+fn test_money_arithmetic() {
+    let dime = Money::new(Decimal::new(1, 1), Currency::Usd);
+    assert_eq!(
+        dime.checked_add(&Money::new(Decimal::new(2, 1), Currency::Usd)),
+        Some(Money::new(Decimal::new(3, 1), Currency::Usd))
+    );
+    assert_eq!(
+        dime.checked_sub(&Money::new(Decimal::new(25, 2), Currency::Usd)),
+        Some(Money::new(Decimal::new(-15, 2), Currency::Usd))
+    );
+    assert_eq!(
+        dime.checked_mul(Decimal::new(15, 1)),
+        Some(Money::new(Decimal::new(15, 2), Currency::Usd))
+    );
+    assert_eq!(
+        dime.checked_add(&Money::new(Decimal::new(1, 1), Currency::Eur)),
+        None
+    );
+    assert_eq!(
+        dime.checked_sub(&Money::new(Decimal::new(1, 1), Currency::Eur)),
+        None
+    )
+}
+
+#[test]
+fn test_money_serde() {
+    let money = Money::new(Decimal::new(34536, 2), Currency::Gbp);
+    assert_eq!(
+        serde_json::to_string(&money).expect("money could not be serialized"),
+        r#"{"amount":345.36,"currency":"GBP"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Money>(r#"{"amount":345.36,"currency":"GBP"}"#)
+            .expect("money could not be deserialized"),
+        money
+    );
+    // exact up to 15 significant digits as amounts are parsed through f64
+    assert_eq!(
+        serde_json::from_str::<Money>(r#"{"amount":9999999999999.99,"currency":"USD"}"#)
+            .expect("money could not be deserialized")
+            .amount,
+        Decimal::new(999999999999999, 2)
+    );
+    assert!(serde_json::from_str::<Money>(r#"{"amount":1e30,"currency":"USD"}"#).is_err())
+}
+
+#[test]
+fn test_money_display() {
+    assert_eq!(
+        Money::new(Decimal::new(50000, 2), Currency::Usd).to_string(),
+        "500.00 USD"
+    )
+}
+
+#[test]
+fn test_currency_display() {
+    assert_eq!(Currency::Jpy.to_string(), "JPY");
+    assert_eq!(Currency::Unknown(String::from("XYZ")).to_string(), "XYZ")
+}
+
+#[test]
+fn test_unknown_variants() {
+    let record_status = serde_json::from_str::<RecordStatus>("\"deleted\"")
+        .expect("record status could not be deserialized");
+    assert_eq!(
+        record_status,
+        RecordStatus::Unknown(String::from("deleted"))
+    );
+    assert_eq!(
+        serde_json::to_string(&record_status).expect("record status could not be serialized"),
+        "\"deleted\""
+    );
+    assert_eq!(
+        serde_json::from_str::<Currency>("\"EUR\"").expect("currency could not be deserialized"),
+        Currency::Eur
+    );
+    assert!(serde_json::from_str::<Status>("1").is_err())
+}
+
+#[test]
+fn test_record_status_display() {
+    assert_eq!(RecordStatus::Archived.to_string(), "archived")
+}
+
+#[test]
+fn test_status_display() {
+    assert_eq!(Status::PendingApproval.to_string(), "pending_approval")
+}
+
+#[test]
+fn test_validation_error_display() {
+    assert_eq!(
+        ValidationError(String::from("name is required")).to_string(),
+        "name is required"
+    )
+}
@@ -2,7 +2,7 @@
 //!
 //! `model::time` is a model for the Ruddr Time Entry object. This module is not publically accessible, but the structs and members are public for reading from `interface::time` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/time-entries/get-a-time-entry.md)
-use crate::model::{shared, types};
+use crate::model::{member, role, shared, types};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -18,7 +18,7 @@ pub struct TimeEntries {
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TimeEntry {
-    pub id: types::UUID,
+    pub id: types::Id<TimeEntry>,
     pub type_id: Type,
     pub status_id: shared::Status,
     pub client_status_id: Option<ClientStatus>,
@@ -28,20 +28,51 @@ pub struct TimeEntry {
     pub notes: Option<String>,
     pub is_billable: bool,
     pub invoiced: bool,
-    pub rate_currency: Option<String>,
-    pub rate: Option<f64>,
-    pub cost_currency: Option<String>,
-    pub cost_per_hour: Option<f64>,
+    pub rate_currency: Option<shared::Currency>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub rate: Option<shared::Decimal>,
+    pub cost_currency: Option<shared::Currency>,
+    #[serde(default, with = "rust_decimal::serde::float_option")]
+    pub cost_per_hour: Option<shared::Decimal>,
     pub created_at: types::Timestamp,
     pub start_time: Option<types::Time>,
     pub end_time: Option<types::Time>,
-    pub member: shared::Entity,
+    pub member: shared::Entity<member::Member>,
     pub project: Option<shared::Project>,
-    pub role: Option<shared::Entity>,
+    pub role: Option<shared::Entity<role::Role>>,
     pub task: Option<shared::Entity>,
     pub time_off_type: Option<shared::Entity>,
     pub timesheet: Option<Timesheet>,
     pub invoice: Option<Invoice>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<TimeEntry, _>")]
+    pub extra: shared::Extra,
+}
+
+impl TimeEntry {
+    /// Returns the minutes between the start and end times if both are specified, accounting for time ranges crossing midnight. This can be compared to `minutes` to check the consistency of the time entry.
+    /// ```ignore
+    /// let consistent = time_entry.range_minutes().is_none_or(|minutes| i64::from(minutes) == time_entry.minutes);
+    /// ```
+    pub fn range_minutes(&self) -> Option<u32> {
+        match (&self.start_time, &self.end_time) {
+            (Some(start_time), Some(end_time)) => Some(start_time.minutes_until(end_time)),
+            _ => None,
+        }
+    }
+
+    /// Returns the billable rate per hour with its currency.
+    pub fn rate_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(self.rate?, self.rate_currency.clone()?))
+    }
+
+    /// Returns the cost per hour with its currency.
+    pub fn cost_per_hour_money(&self) -> Option<shared::Money> {
+        Some(shared::Money::new(
+            self.cost_per_hour?,
+            self.cost_currency.clone()?,
+        ))
+    }
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -50,6 +81,9 @@ pub struct Invoice {
     pub id: types::UUID,
     pub number: String,
     pub line: Option<Line>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Invoice, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -57,6 +91,9 @@ pub struct Invoice {
 pub struct Line {
     pub id: types::UUID,
     pub number: i64,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Line, _>")]
+    pub extra: shared::Extra,
 }
 
 #[derive(PartialEq, Deserialize, Serialize, Debug)]
@@ -64,6 +101,9 @@ pub struct Line {
 pub struct Timesheet {
     pub id: types::UUID,
     pub submitted_on: types::Timestamp,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Timesheet, _>")]
+    pub extra: shared::Extra,
 }
 
 // custom types: enum
@@ -72,6 +112,9 @@ pub struct Timesheet {
 pub enum Type {
     ProjectTime,
     TimeOff,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl fmt::Display for Type {
@@ -92,6 +135,20 @@ pub enum ClientStatus {
     PendingApproval,
     Approved,
     Rejected,
+    /// Value not yet recognized by this crate, preserved as returned by the Ruddr API.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl fmt::Display for ClientStatus {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        // use serialize for automatic snake case from trait derivation, but then remove extraneous " chars incurred during JSON formatting
+        write!(
+            format,
+            "{}",
+            serde_json::to_string(self).unwrap().replace("\"", "")
+        )
+    }
 }
 
 #[cfg(test)]
@@ -58,56 +58,79 @@ fn test_time_deserialize() {
     let time_entry_deserialized = serde_json::from_str::<TimeEntry>(json_input)
         .expect("time entry could not be deserialized");
     let time_entry = TimeEntry {
-        id: types::UUID(String::from("4497fa99-27a4-4509-9748-83e4399296e3")),
+        id: types::Id::try_from("4497fa99-27a4-4509-9748-83e4399296e3")
+            .expect("uuid conversion failed"),
         type_id: Type::ProjectTime,
         status_id: shared::Status::Approved,
         client_status_id: Some(ClientStatus::NotCreated),
         date: types::Date(String::from("2022-03-08")),
         minutes: 120,
-        timer_started_at: Some(types::Timestamp(String::from("2022-03-10T17:50:48.808Z"))),
+        timer_started_at: Some(
+            types::Timestamp::try_from("2022-03-10T17:50:48.808Z")
+                .expect("timestamp conversion failed"),
+        ),
         notes: None,
         is_billable: true,
         invoiced: true,
-        rate_currency: Some(String::from("USD")),
-        rate: Some(100.0),
-        cost_currency: Some(String::from("USD")),
-        cost_per_hour: Some(50.0),
-        created_at: types::Timestamp(String::from("2022-03-11T16:13:40.715Z")),
+        rate_currency: Some(shared::Currency::Usd),
+        rate: Some(shared::Decimal::from(100)),
+        cost_currency: Some(shared::Currency::Usd),
+        cost_per_hour: Some(shared::Decimal::from(50)),
+        created_at: types::Timestamp::try_from("2022-03-11T16:13:40.715Z")
+            .expect("timestamp conversion failed"),
         start_time: Some(types::Time(String::from("01:00"))),
         end_time: Some(types::Time(String::from("03:00"))),
         member: shared::Entity {
-            id: types::UUID(String::from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")),
+            id: types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                .expect("uuid conversion failed"),
             name: String::from("John Smith"),
+            extra: shared::Extra::new(),
         },
         project: Some(shared::Project {
-            id: types::UUID(String::from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")),
+            id: types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                .expect("uuid conversion failed"),
             name: String::from("Vendor Portal"),
             client: shared::Entity {
-                id: types::UUID(String::from("4cacdf11-71d1-4fbb-90ee-b091803581b0")),
+                id: types::Id::try_from("4cacdf11-71d1-4fbb-90ee-b091803581b0")
+                    .expect("uuid conversion failed"),
                 name: String::from("Joe's Shop"),
+                extra: shared::Extra::new(),
             },
+            extra: shared::Extra::new(),
         }),
         role: Some(shared::Entity {
-            id: types::UUID(String::from("7ad5a34a-07b7-48e9-a760-bd220d52e354")),
+            id: types::Id::try_from("7ad5a34a-07b7-48e9-a760-bd220d52e354")
+                .expect("uuid conversion failed"),
             name: String::from("Project Manager"),
+            extra: shared::Extra::new(),
         }),
         task: Some(shared::Entity {
-            id: types::UUID(String::from("9a7097a0-d71e-4ed2-9bc3-2dd7d797edc4")),
+            id: types::Id::try_from("9a7097a0-d71e-4ed2-9bc3-2dd7d797edc4")
+                .expect("uuid conversion failed"),
             name: String::from("Project Status Reporting"),
+            extra: shared::Extra::new(),
         }),
         time_off_type: Some(shared::Entity {
-            id: types::UUID(String::from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")),
+            id: types::Id::try_from("8fc28b3d-e179-4193-bbdd-09387be8a1e9")
+                .expect("uuid conversion failed"),
             name: String::from("Holiday"),
+            extra: shared::Extra::new(),
         }),
         timesheet: Some(Timesheet {
-            id: types::UUID(String::from("8c3f7a92-e14b-4d60-b5a1-2f09d7c63e18")),
-            submitted_on: types::Timestamp(String::from("2025-01-19T17:32:10.541Z")),
+            id: types::UUID::try_from("8c3f7a92-e14b-4d60-b5a1-2f09d7c63e18")
+                .expect("uuid conversion failed"),
+            submitted_on: types::Timestamp::try_from("2025-01-19T17:32:10.541Z")
+                .expect("timestamp conversion failed"),
+            extra: shared::Extra::new(),
         }),
         invoice: Some(Invoice {
-            id: types::UUID(String::from("86a4f846-3e3b-43a2-bd9b-f5afabdad447")),
+            id: types::UUID::try_from("86a4f846-3e3b-43a2-bd9b-f5afabdad447")
+                .expect("uuid conversion failed"),
             number: String::from("INV-0001"),
             line: None,
+            extra: shared::Extra::new(),
         }),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         time_entry_deserialized, time_entry,
@@ -130,3 +153,104 @@ fn test_time_deserialize() {
         "time_entries did not contain the expected values"
     );
 }
+
+#[test]
+fn test_time_entry_range_minutes() {
+    let time_entry = serde_json::from_str::<TimeEntry>(
+        r#"
+        {
+          "id": "ec5543de-3b0f-47a0-b8ef-a6e18dc4b885",
+          "typeId": "project_time",
+          "statusId": "approved",
+          "clientStatusId": null,
+          "date": "2022-03-08",
+          "minutes": 150,
+          "timerStartedAt": null,
+          "notes": null,
+          "isBillable": true,
+          "invoiced": false,
+          "rateCurrency": null,
+          "rate": null,
+          "costCurrency": null,
+          "costPerHour": null,
+          "createdAt": "2022-03-15T14:59:18.825Z",
+          "startTime": "22:30",
+          "endTime": "01:00",
+          "member": {
+            "id": "3f3df320-dd95-4a42-8eae-99243fb2ea86",
+            "name": "John Smith"
+          },
+          "project": null,
+          "role": null,
+          "task": null,
+          "timeOffType": null,
+          "timesheet": null,
+          "invoice": null
+        }"#,
+    )
+    .expect("time entry could not be deserialized");
+    assert_eq!(time_entry.range_minutes(), Some(150));
+    assert_eq!(
+        TimeEntry {
+            start_time: None,
+            ..time_entry
+        }
+        .range_minutes(),
+        None
+    )
+}
+
+#[test]
+fn test_time_entry_money() {
+    let time_entry = serde_json::from_str::<TimeEntry>(
+        r#"
+        {
+          "id": "ec5543de-3b0f-47a0-b8ef-a6e18dc4b885",
+          "typeId": "project_time",
+          "statusId": "approved",
+          "clientStatusId": null,
+          "date": "2022-03-08",
+          "minutes": 90,
+          "timerStartedAt": null,
+          "notes": null,
+          "isBillable": true,
+          "invoiced": false,
+          "rateCurrency": "EUR",
+          "rate": 120.1,
+          "costCurrency": null,
+          "costPerHour": 50,
+          "createdAt": "2022-03-15T14:59:18.825Z",
+          "startTime": null,
+          "endTime": null,
+          "member": {
+            "id": "3f3df320-dd95-4a42-8eae-99243fb2ea86",
+            "name": "John Smith"
+          },
+          "project": null,
+          "role": null,
+          "task": null,
+          "timeOffType": null,
+          "timesheet": null,
+          "invoice": null
+        }"#,
+    )
+    .expect("time entry could not be deserialized");
+    assert_eq!(
+        time_entry.rate_money(),
+        Some(shared::Money::new(
+            shared::Decimal::new(1201, 1),
+            shared::Currency::Eur
+        ))
+    );
+    assert_eq!(time_entry.cost_per_hour_money(), None)
+}
+
+#[test]
+fn test_type_display() {
+    assert_eq!(Type::TimeOff.to_string(), "time_off")
+}
+
+#[test]
+fn test_client_status_display() {
+    assert_eq!(ClientStatus::NotCreated.to_string(), "not_created")
+}
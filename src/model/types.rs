@@ -2,8 +2,11 @@
 //!
 //! `model::types` defines custom type structs with trait implementations corresponding to Ruddr API custom types.
 use regex::Regex;
-use serde::{Deserializer, Serialize};
+use serde::{Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::LazyLock;
 
 /// Aggregation of error types for conversions defined as tuple variants.
 #[derive(Debug, PartialEq)]
@@ -27,25 +30,141 @@ impl fmt::Display for TypeError {
     }
 }
 
+// validators are compiled once on first use and then shared across all conversions
+// digits are restricted to ascii as regex \d also matches other unicode digits which cannot be parsed as numbers
+static DATE_VALIDATOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[0-9]{4}-[0-9]{2}-[0-9]{2}$").unwrap());
+static TIMESTAMP_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^([0-9]{4})-([0-9]{2})-([0-9]{2})T([0-9]{2}):([0-9]{2}):([0-9]{2})(?:\.([0-9]{1,9}))?(?:Z|([+-])([0-9]{2}):([0-9]{2}))$",
+    )
+    .unwrap()
+});
+static TIME_VALIDATOR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([01][0-9]|2[0-3]):[0-5][0-9]$").unwrap());
+#[cfg(not(feature = "uuid"))]
+static UUID_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+        .unwrap()
+});
+static SLUG_VALIDATOR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z0-9-]+$").unwrap());
+
 /// Custom type for Ruddr Date type in YYYY-MM-DD format.
 /// Consumers are expected to instantiate this through type conversion, and not the implicit or explicit constructors.
+/// The date must be a valid calendar date, and dates are ordered chronologically.
 /// ```ignore
 /// Date::try_from("2028-12-31")
 /// ```
-#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+/// Conversions to and from `chrono::NaiveDate` and `time::Date` are available with the `chrono` and `time` features respectively.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Serialize)]
 // public access to the type should exist, but not to the implicit constructor as users are expected to instantiate through type converters each containing an invocation to the explicit constructor
 pub struct Date(pub(super) String);
 
 impl Date {
     // constructor with validation used within type converters
     fn new(date: String) -> Result<Self, TypeError> {
-        let date_validator = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
-        if date_validator.is_match(&date) {
-            Ok(Date(date))
+        if DATE_VALIDATOR.is_match(&date) {
+            // validate date also exists in the calendar
+            let date = Date(date);
+            let (year, month, day) = date.components();
+            if (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day) {
+                return Ok(date);
+            }
+            Err(TypeError::DateError(format!("invalid date: {}", date.0)))
         } else {
             Err(TypeError::DateError(format!("invalid date: {date}")))
         }
     }
+
+    // constructor from calendar components with validation of the four digit year range
+    fn from_ymd(year: i32, month: u32, day: u32) -> Result<Self, TypeError> {
+        Date::new(format!("{year:04}-{month:02}-{day:02}"))
+    }
+
+    // constructor from days since unix epoch with validation of the four digit year range
+    fn from_days(days: i64) -> Option<Self> {
+        if !(days_from_civil(0, 1, 1)..=days_from_civil(9999, 12, 31)).contains(&days) {
+            return None;
+        }
+        let (year, month, day) = civil_from_days(days);
+        Date::from_ymd(year, month, day).ok()
+    }
+
+    // calendar components of the already validated date
+    fn components(&self) -> (i32, u32, u32) {
+        (
+            self.0[0..4].parse().unwrap(),
+            self.0[5..7].parse().unwrap(),
+            self.0[8..10].parse().unwrap(),
+        )
+    }
+
+    // days since unix epoch
+    fn days(&self) -> i64 {
+        let (year, month, day) = self.components();
+        days_from_civil(year, month, day)
+    }
+
+    /// Returns the calendar year.
+    pub fn year(&self) -> i32 {
+        self.components().0
+    }
+
+    /// Returns the calendar month starting from 1.
+    pub fn month(&self) -> u32 {
+        self.components().1
+    }
+
+    /// Returns the day of the month starting from 1.
+    pub fn day(&self) -> u32 {
+        self.components().2
+    }
+
+    /// Returns the ISO 8601 day of the week from Monday (1) to Sunday (7).
+    pub fn weekday(&self) -> u32 {
+        // unix epoch was a thursday
+        ((self.days() + 3).rem_euclid(7) + 1) as u32
+    }
+
+    /// Returns the following date, or `None` if it exceeds the four digit year range.
+    pub fn succ(&self) -> Option<Self> {
+        self.add_days(1)
+    }
+
+    /// Returns the preceding date, or `None` if it precedes the four digit year range.
+    pub fn pred(&self) -> Option<Self> {
+        self.add_days(-1)
+    }
+
+    /// Returns the date offset by the number of days (which may be negative), or `None` if the result is outside the four digit year range.
+    /// ```ignore
+    /// Date::try_from("2024-02-28")?.add_days(2) // Some(2024-03-01)
+    /// ```
+    pub fn add_days(&self, days: i64) -> Option<Self> {
+        Date::from_days(self.days().checked_add(days)?)
+    }
+
+    /// Returns the number of days from the other date to this date, which is negative if the other date is later.
+    pub fn days_since(&self, other: &Date) -> i64 {
+        self.days() - other.days()
+    }
+
+    /// Returns the Monday starting the ISO 8601 week containing this date, or `None` if it precedes the four digit year range.
+    pub fn start_of_week(&self) -> Option<Self> {
+        self.add_days(1 - i64::from(self.weekday()))
+    }
+
+    /// Returns the first day of the month containing this date.
+    pub fn start_of_month(&self) -> Self {
+        let (year, month, _) = self.components();
+        Date::from_ymd(year, month, 1).unwrap()
+    }
+
+    /// Returns the last day of the month containing this date.
+    pub fn end_of_month(&self) -> Self {
+        let (year, month, _) = self.components();
+        Date::from_ymd(year, month, days_in_month(year, month)).unwrap()
+    }
 }
 
 impl TryFrom<String> for Date {
@@ -94,29 +213,219 @@ impl fmt::Display for Date {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<&Date> for chrono::NaiveDate {
+    fn from(date: &Date) -> Self {
+        let (year, month, day) = date.components();
+        chrono::NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Date> for chrono::NaiveDate {
+    fn from(date: Date) -> Self {
+        chrono::NaiveDate::from(&date)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = TypeError;
+
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+        Date::from_ymd(date.year(), date.month(), date.day())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<&Date> for time::Date {
+    fn from(date: &Date) -> Self {
+        let (year, month, day) = date.components();
+        time::Date::from_calendar_date(year, time::Month::try_from(month as u8).unwrap(), day as u8)
+            .unwrap()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Date> for time::Date {
+    fn from(date: Date) -> Self {
+        time::Date::from(&date)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for Date {
+    type Error = TypeError;
+
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        Date::from_ymd(
+            date.year(),
+            u32::from(u8::from(date.month())),
+            u32::from(date.day()),
+        )
+    }
+}
+
+// number of days in the month of the year according to the proleptic gregorian calendar
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// days since unix epoch from the calendar date (http://howardhinnant.github.io/date_algorithms.html#days_from_civil)
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// calendar date from days since unix epoch (http://howardhinnant.github.io/date_algorithms.html#civil_from_days)
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
 /// Custom type for Ruddr Timestamp type in YYYY-MM-DDThh:mm:ss.msZ format where "T" is literal.
-/// This is most similar to ISO 8601 extended format with milliseconds for reference.
+/// This is most similar to ISO 8601 extended format with milliseconds for reference. Any fractional second precision up to nanoseconds (including none) and UTC offsets in place of "Z" are also accepted, and the timestamp is stored as a UTC instant.
 /// Consumers are expected to instantiate this through type conversion, and not the implicit or explicit constructors.
 /// ```ignore
-/// Timestamp::try_from("1234-56-78T12:34:56.789Z")
+/// Timestamp::try_from("2022-03-15T14:59:18.825Z")
 /// ```
-/// This type is currently unused in input parameters to interface functions, but is still public for potential unforeseen usage.
-#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+/// Timestamps are ordered chronologically, and convert to and from `std::time::SystemTime` for computing elapsed durations. Conversions to and from `chrono::DateTime` and `time::OffsetDateTime` (including local time zones) are available with the `chrono` and `time` features respectively.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
 // public access to the type should exist, but not to the implicit constructor as users are expected to instantiate through type converters each containing an invocation to the explicit constructor
-pub struct Timestamp(pub(super) String);
+pub struct Timestamp {
+    // seconds since unix epoch
+    pub(super) seconds: i64,
+    // nanoseconds within the second
+    pub(super) nanoseconds: u32,
+}
 
 impl Timestamp {
     // constructor with validation used within type converters
     fn new(timestamp: String) -> Result<Self, TypeError> {
-        let timestamp_validator =
-            Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z$").unwrap();
-        if timestamp_validator.is_match(&timestamp) {
-            Ok(Timestamp(timestamp))
-        } else {
-            Err(TypeError::TimestampError(format!(
-                "invalid timestamp: {timestamp}"
-            )))
+        let error = || TypeError::TimestampError(format!("invalid timestamp: {timestamp}"));
+        let captures = TIMESTAMP_VALIDATOR.captures(&timestamp).ok_or_else(error)?;
+        // captures are validated ascii digits at this point, but parse failures are still reported rather than panicking
+        let number = |index: usize| -> Result<i64, TypeError> {
+            captures[index].parse().map_err(|_| error())
+        };
+
+        // validate date and time components
+        let (year, month, day) = (number(1)? as i32, number(2)? as u32, number(3)? as u32);
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return Err(error());
+        }
+        let (hour, minute, second) = (number(4)?, number(5)?, number(6)?);
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(error());
         }
+        // right pad fraction to nanoseconds
+        let nanoseconds = match captures.get(7) {
+            Some(fraction) => format!("{:0<9}", fraction.as_str())
+                .parse()
+                .map_err(|_| error())?,
+            None => 0,
+        };
+        // normalize offset to utc
+        let offset = match captures.get(8) {
+            Some(sign) => {
+                let (offset_hour, offset_minute) = (number(9)?, number(10)?);
+                if offset_hour > 23 || offset_minute > 59 {
+                    return Err(error());
+                }
+                let offset = offset_hour * 3600 + offset_minute * 60;
+                if sign.as_str() == "-" {
+                    -offset
+                } else {
+                    offset
+                }
+            }
+            None => 0,
+        };
+
+        // normalized instant may fall outside the four digit year range
+        Timestamp::from_unix(
+            days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset,
+            nanoseconds,
+        )
+        .map_err(|_| error())
+    }
+
+    // constructor from unix time with validation of the four digit year range
+    fn from_unix(seconds: i64, nanoseconds: u32) -> Result<Self, TypeError> {
+        if Date::from_days(seconds.div_euclid(86400)).is_none() || nanoseconds >= 1_000_000_000 {
+            return Err(TypeError::TimestampError(format!(
+                "invalid timestamp: {seconds}s {nanoseconds}ns since unix epoch"
+            )));
+        }
+        Ok(Timestamp {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Returns the current instant.
+    pub fn now() -> Self {
+        Timestamp::try_from(std::time::SystemTime::now()).unwrap()
+    }
+
+    /// Returns the number of whole seconds since the unix epoch.
+    pub fn unix_seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// Returns the nanoseconds within the second.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanoseconds
+    }
+
+    /// Returns the UTC calendar date of the timestamp.
+    pub fn date(&self) -> Date {
+        Date::from_days(self.seconds.div_euclid(86400)).unwrap()
+    }
+
+    /// Returns the duration elapsed from the earlier timestamp to this timestamp, or `None` if the earlier timestamp is actually later.
+    /// ```ignore
+    /// let created_recently = Timestamp::now().duration_since(&time_entry.created_at) < Some(Duration::from_secs(86400));
+    /// ```
+    pub fn duration_since(&self, earlier: &Timestamp) -> Option<std::time::Duration> {
+        std::time::SystemTime::from(*self)
+            .duration_since(std::time::SystemTime::from(*earlier))
+            .ok()
+    }
+
+    /// Returns the timestamp converted to the local time zone.
+    #[cfg(feature = "chrono")]
+    pub fn to_local(&self) -> chrono::DateTime<chrono::Local> {
+        chrono::DateTime::<chrono::Utc>::from(*self).with_timezone(&chrono::Local)
+    }
+
+    /// Returns the timestamp converted to the UTC offset e.g. the local offset from `time::UtcOffset::current_local_offset`.
+    #[cfg(feature = "time")]
+    pub fn to_offset(&self, offset: time::UtcOffset) -> time::OffsetDateTime {
+        time::OffsetDateTime::from(*self).to_offset(offset)
     }
 }
 
@@ -138,13 +447,84 @@ impl TryFrom<&str> for Timestamp {
 
 impl From<Timestamp> for String {
     fn from(timestamp: Timestamp) -> Self {
-        timestamp.0
+        timestamp.to_string()
+    }
+}
+
+// replaces the borrowed string conversion as timestamps are no longer stored as strings
+impl From<&Timestamp> for String {
+    fn from(timestamp: &Timestamp) -> Self {
+        timestamp.to_string()
+    }
+}
+
+impl From<Timestamp> for std::time::SystemTime {
+    fn from(timestamp: Timestamp) -> Self {
+        // nanoseconds are always forward within the second
+        let nanoseconds = std::time::Duration::from_nanos(u64::from(timestamp.nanoseconds));
+        let seconds = std::time::Duration::from_secs(timestamp.seconds.unsigned_abs());
+        if timestamp.seconds < 0 {
+            std::time::UNIX_EPOCH - seconds + nanoseconds
+        } else {
+            std::time::UNIX_EPOCH + seconds + nanoseconds
+        }
+    }
+}
+
+impl TryFrom<std::time::SystemTime> for Timestamp {
+    type Error = TypeError;
+
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let (seconds, nanoseconds) = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since_epoch) => (since_epoch.as_secs() as i64, since_epoch.subsec_nanos()),
+            Err(error) => {
+                // borrow a second for the forward nanoseconds within the second
+                let before_epoch = error.duration();
+                match before_epoch.subsec_nanos() {
+                    0 => (-(before_epoch.as_secs() as i64), 0),
+                    nanoseconds => (
+                        -(before_epoch.as_secs() as i64) - 1,
+                        1_000_000_000 - nanoseconds,
+                    ),
+                }
+            }
+        };
+        Timestamp::from_unix(seconds, nanoseconds)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Timestamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: Timestamp) -> Self {
+        chrono::DateTime::from_timestamp(timestamp.seconds, timestamp.nanoseconds).unwrap()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for Timestamp {
+    type Error = TypeError;
+
+    fn try_from(datetime: chrono::DateTime<Tz>) -> Result<Self, Self::Error> {
+        Timestamp::from_unix(datetime.timestamp(), datetime.timestamp_subsec_nanos())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<Timestamp> for time::OffsetDateTime {
+    fn from(timestamp: Timestamp) -> Self {
+        time::OffsetDateTime::from_unix_timestamp(timestamp.seconds)
+            .unwrap()
+            .replace_nanosecond(timestamp.nanoseconds)
+            .unwrap()
     }
 }
 
-impl<'timestamp> From<&'timestamp Timestamp> for &'timestamp str {
-    fn from(timestamp: &'timestamp Timestamp) -> Self {
-        &timestamp.0
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = TypeError;
+
+    fn try_from(datetime: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        Timestamp::from_unix(datetime.unix_timestamp(), datetime.nanosecond())
     }
 }
 
@@ -154,37 +534,118 @@ impl<'de> serde::Deserialize<'de> for Timestamp {
     }
 }
 
-/*impl serde::Serialize for Timestamp {
+impl serde::Serialize for Timestamp {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.0)
+        serializer.serialize_str(&self.to_string())
     }
-}*/
+}
 
 impl fmt::Display for Timestamp {
     fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
-        write!(format, "{}", self.0)
+        // utc date and time of day
+        let date = self.date();
+        let second_of_day = self.seconds.rem_euclid(86400);
+        write!(
+            format,
+            "{date}T{:02}:{:02}:{:02}",
+            second_of_day / 3600,
+            second_of_day % 3600 / 60,
+            second_of_day % 60
+        )?;
+        // fractional seconds in the least precision of milliseconds, microseconds, and nanoseconds without loss
+        if self.nanoseconds.is_multiple_of(1_000_000) {
+            write!(format, ".{:03}Z", self.nanoseconds / 1_000_000)
+        } else if self.nanoseconds.is_multiple_of(1_000) {
+            write!(format, ".{:06}Z", self.nanoseconds / 1_000)
+        } else {
+            write!(format, ".{:09}Z", self.nanoseconds)
+        }
     }
 }
 
 /// Custom type for Ruddr Time type in hh:mm format.
 /// Consumers are expected to instantiate this through type conversion, and not the implicit or explicit constructors.
+/// The hours must be within 00-23 and the minutes within 00-59, and times are ordered chronologically within the day.
 /// ```ignore
 /// Time::try_from("12:34")
 /// ```
-#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+/// Times convert to and from the `std::time::Duration` since midnight, and the duration between two times accounts for ranges crossing midnight.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Serialize)]
 // public access to the type should exist, but not to the implicit constructor as users are expected to instantiate through type converters each containing an invocation to the explicit constructor
 pub struct Time(pub(super) String);
 
 impl Time {
     // constructor with validation used within type converters
     fn new(time: String) -> Result<Self, TypeError> {
-        let time_validator = Regex::new(r"^\d{2}:\d{2}$").unwrap();
-        if time_validator.is_match(&time) {
+        if TIME_VALIDATOR.is_match(&time) {
             Ok(Time(time))
         } else {
             Err(TypeError::TimeError(format!("invalid time: {time}")))
         }
     }
+
+    /// Instantiates the time from the minutes since midnight, which must be less than one day.
+    /// ```ignore
+    /// Time::from_minutes(754) // 12:34
+    /// ```
+    pub fn from_minutes(minutes: u32) -> Result<Self, TypeError> {
+        if minutes >= 1440 {
+            return Err(TypeError::TimeError(format!(
+                "invalid time: {minutes} minutes since midnight"
+            )));
+        }
+        Ok(Time(format!("{:02}:{:02}", minutes / 60, minutes % 60)))
+    }
+
+    /// Returns the hour of the day.
+    pub fn hour(&self) -> u32 {
+        self.0[0..2].parse().unwrap()
+    }
+
+    /// Returns the minute of the hour.
+    pub fn minute(&self) -> u32 {
+        self.0[3..5].parse().unwrap()
+    }
+
+    /// Returns the minutes since midnight.
+    pub fn minutes(&self) -> u32 {
+        self.hour() * 60 + self.minute()
+    }
+
+    /// Returns the minutes from this time until the end time, wrapping past midnight if the end time is earlier than this time.
+    /// ```ignore
+    /// Time::try_from("22:30")?.minutes_until(&Time::try_from("01:00")?) // 150
+    /// ```
+    pub fn minutes_until(&self, end: &Time) -> u32 {
+        (end.minutes() + 1440 - self.minutes()) % 1440
+    }
+
+    /// Returns the duration from this time until the end time, wrapping past midnight if the end time is earlier than this time.
+    pub fn duration_until(&self, end: &Time) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(self.minutes_until(end)) * 60)
+    }
+}
+
+impl From<&Time> for std::time::Duration {
+    fn from(time: &Time) -> Self {
+        std::time::Duration::from_secs(u64::from(time.minutes()) * 60)
+    }
+}
+
+impl From<Time> for std::time::Duration {
+    fn from(time: Time) -> Self {
+        std::time::Duration::from(&time)
+    }
+}
+
+impl TryFrom<std::time::Duration> for Time {
+    type Error = TypeError;
+
+    // truncates to whole minutes
+    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {
+        let minutes = u32::try_from(duration.as_secs() / 60).unwrap_or(u32::MAX);
+        Time::from_minutes(minutes)
+    }
 }
 
 impl TryFrom<String> for Time {
@@ -235,26 +696,47 @@ impl fmt::Display for Time {
 
 /// Custom type for Ruddr UUID type in standard format.
 /// Consumers are expected to instantiate this through type conversion, and not the implicit or explicit constructors.
+/// With the `uuid` feature enabled the value is stored as a `uuid::Uuid` (16 bytes and `Copy`), and otherwise as the validated string. In both cases the value is normalized to lowercase, so equality, ordering, and display do not depend upon feature selection.
 /// ```ignore
 /// UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
 /// ```
-#[derive(PartialEq, Eq, Debug, Clone, Serialize)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone)]
+#[cfg_attr(feature = "uuid", derive(Copy))]
 // public access to the type should exist, but not to the implicit constructor as users are expected to instantiate through type converters each containing an invocation to the explicit constructor
-pub struct UUID(pub(super) String);
+pub struct UUID(pub(super) UUIDValue);
+
+// storage for the uuid value dependent upon feature selection
+#[cfg(feature = "uuid")]
+pub(super) type UUIDValue = uuid::Uuid;
+#[cfg(not(feature = "uuid"))]
+pub(super) type UUIDValue = String;
 
 impl UUID {
     // constructor with validation used within type converters
+    #[cfg(not(feature = "uuid"))]
     fn new(uuid: String) -> Result<Self, TypeError> {
-        let uuid_validator = Regex::new(
-            r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
-        )
-        .unwrap();
-        if uuid_validator.is_match(&uuid) {
-            Ok(UUID(uuid))
+        // normalize case consistently with the uuid crate
+        if UUID_VALIDATOR.is_match(&uuid) {
+            Ok(UUID(uuid.to_ascii_lowercase()))
         } else {
             Err(TypeError::UUIDError(format!("invalid uuid: {uuid}")))
         }
     }
+
+    // constructor with validation used within type converters
+    #[cfg(feature = "uuid")]
+    fn new(uuid: String) -> Result<Self, TypeError> {
+        UUID::parse(&uuid)
+    }
+
+    // uuid crate also parses simple, braced, and urn formats, so restrict to the hyphenated standard format length
+    #[cfg(feature = "uuid")]
+    fn parse(uuid: &str) -> Result<Self, TypeError> {
+        match uuid::Uuid::try_parse(uuid) {
+            Ok(parsed) if uuid.len() == 36 => Ok(UUID(parsed)),
+            _ => Err(TypeError::UUIDError(format!("invalid uuid: {uuid}"))),
+        }
+    }
 }
 
 impl TryFrom<String> for UUID {
@@ -268,20 +750,48 @@ impl TryFrom<String> for UUID {
 impl TryFrom<&str> for UUID {
     type Error = TypeError;
 
+    #[cfg(not(feature = "uuid"))]
     fn try_from(uuid: &str) -> Result<Self, Self::Error> {
         UUID::new(String::from(uuid))
     }
+
+    // avoid the intermediate string allocation
+    #[cfg(feature = "uuid")]
+    fn try_from(uuid: &str) -> Result<Self, Self::Error> {
+        UUID::parse(uuid)
+    }
 }
 
 impl From<UUID> for String {
+    #[cfg(not(feature = "uuid"))]
     fn from(uuid: UUID) -> Self {
         uuid.0
     }
+
+    #[cfg(feature = "uuid")]
+    fn from(uuid: UUID) -> Self {
+        uuid.to_string()
+    }
+}
+
+// replaces the borrowed string conversion as the uuid feature stores no string
+impl From<&UUID> for String {
+    fn from(uuid: &UUID) -> Self {
+        uuid.to_string()
+    }
 }
 
-impl<'uuid> From<&'uuid UUID> for &'uuid str {
-    fn from(uuid: &'uuid UUID) -> Self {
-        &uuid.0
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for UUID {
+    fn from(uuid: uuid::Uuid) -> Self {
+        UUID(uuid)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<UUID> for uuid::Uuid {
+    fn from(uuid: UUID) -> Self {
+        uuid.0
     }
 }
 
@@ -291,11 +801,12 @@ impl<'de> serde::Deserialize<'de> for UUID {
     }
 }
 
-/*impl serde::Serialize for UUID {
+// serialize as the hyphenated string for both storage types
+impl Serialize for UUID {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_str(&self.0)
+        serializer.collect_str(&self.0)
     }
-}*/
+}
 
 impl fmt::Display for UUID {
     fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
@@ -303,6 +814,129 @@ impl fmt::Display for UUID {
     }
 }
 
+/// Custom type for the UUID of a specific Ruddr resource, so that e.g. a member id cannot be passed where a project id is expected.
+/// The resource is a phantom type parameter, and the id converts to and from `UUID` for interoperability.
+/// ```ignore
+/// let id: Id<member::Member> = Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")?;
+/// let uuid = UUID::from(id);
+/// ```
+// manual trait implementations avoid the bounds on the resource type which derivation would require
+pub struct Id<Resource> {
+    uuid: UUID,
+    resource: PhantomData<fn() -> Resource>,
+}
+
+impl<Resource> Id<Resource> {
+    /// Returns the untyped UUID of the resource.
+    pub fn uuid(&self) -> &UUID {
+        &self.uuid
+    }
+}
+
+impl<Resource> From<UUID> for Id<Resource> {
+    fn from(uuid: UUID) -> Self {
+        Id {
+            uuid,
+            resource: PhantomData,
+        }
+    }
+}
+
+impl<Resource> From<Id<Resource>> for UUID {
+    fn from(id: Id<Resource>) -> Self {
+        id.uuid
+    }
+}
+
+impl<Resource> TryFrom<String> for Id<Resource> {
+    type Error = TypeError;
+
+    fn try_from(uuid: String) -> Result<Self, Self::Error> {
+        Ok(Id::from(UUID::try_from(uuid)?))
+    }
+}
+
+impl<Resource> TryFrom<&str> for Id<Resource> {
+    type Error = TypeError;
+
+    fn try_from(uuid: &str) -> Result<Self, Self::Error> {
+        Ok(Id::from(UUID::try_from(uuid)?))
+    }
+}
+
+impl<Resource> From<Id<Resource>> for String {
+    fn from(id: Id<Resource>) -> Self {
+        String::from(id.uuid)
+    }
+}
+
+#[cfg(not(feature = "uuid"))]
+impl<Resource> Clone for Id<Resource> {
+    fn clone(&self) -> Self {
+        Id::from(self.uuid.clone())
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<Resource> Clone for Id<Resource> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl<Resource> Copy for Id<Resource> {}
+
+impl<Resource> PartialEq for Id<Resource> {
+    fn eq(&self, other: &Self) -> bool {
+        self.uuid == other.uuid
+    }
+}
+
+impl<Resource> Eq for Id<Resource> {}
+
+impl<Resource> PartialOrd for Id<Resource> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Resource> Ord for Id<Resource> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.uuid.cmp(&other.uuid)
+    }
+}
+
+impl<Resource> Hash for Id<Resource> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.uuid.hash(state)
+    }
+}
+
+impl<Resource> fmt::Debug for Id<Resource> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        format.debug_tuple("Id").field(&self.uuid).finish()
+    }
+}
+
+impl<'de, Resource> serde::Deserialize<'de> for Id<Resource> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Id::from(UUID::deserialize(deserializer)?))
+    }
+}
+
+impl<Resource> Serialize for Id<Resource> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.uuid.serialize(serializer)
+    }
+}
+
+impl<Resource> fmt::Display for Id<Resource> {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        write!(format, "{}", self.uuid)
+    }
+}
+
 /// Custom type for Ruddr Slug type in standard format.
 /// Consumers are expected to instantiate this through type conversion, and not the implicit or explicit constructors.
 /// ```ignore
@@ -315,8 +949,7 @@ pub struct Slug(pub(super) String);
 impl Slug {
     // constructor with validation used within type converters
     fn new(slug: String) -> Result<Self, TypeError> {
-        let slug_validator = Regex::new(r"^[a-z0-9-]+$").unwrap();
-        if slug_validator.is_match(&slug) {
+        if SLUG_VALIDATOR.is_match(&slug) {
             Ok(Slug(slug))
         } else {
             Err(TypeError::SlugError(format!("invalid slug: {slug}")))
@@ -3,8 +3,8 @@ use super::*;
 #[test]
 fn test_date_new() {
     assert_eq!(
-        Date(String::from("1234-56-78")),
-        Date::new(String::from("1234-56-78")).expect("date conversion failed")
+        Date(String::from("1234-05-18")),
+        Date::new(String::from("1234-05-18")).expect("date conversion failed")
     )
 }
 
@@ -14,61 +14,226 @@ fn test_date_new_error() {
         Date::new(String::from("99-99-9999")).unwrap_err(),
         TypeError::DateError(String::from("invalid date: 99-99-9999"))
     );
+    // non-ascii digits
+    assert_eq!(
+        Date::new(String::from("٢٠٢٤-01-01")).unwrap_err(),
+        TypeError::DateError(String::from("invalid date: ٢٠٢٤-01-01"))
+    );
+    assert!(Date::try_from("2024-0١-01").is_err());
+    assert!(serde_json::from_str::<Date>("\"2024-0١-01\"").is_err());
+}
+
+#[test]
+fn test_date_new_calendar_error() {
+    assert_eq!(
+        Date::new(String::from("2024-13-45")).unwrap_err(),
+        TypeError::DateError(String::from("invalid date: 2024-13-45"))
+    );
+    assert_eq!(
+        Date::new(String::from("2023-02-29")).unwrap_err(),
+        TypeError::DateError(String::from("invalid date: 2023-02-29"))
+    );
+    assert_eq!(
+        Date(String::from("2024-02-29")),
+        Date::new(String::from("2024-02-29")).expect("leap day conversion failed")
+    )
 }
 
 #[test]
 fn test_date_from_str() {
     assert_eq!(
-        Date(String::from("1234-56-78")),
-        Date::try_from("1234-56-78").expect("date conversion failed")
+        Date(String::from("1234-05-18")),
+        Date::try_from("1234-05-18").expect("date conversion failed")
     )
 }
 
 #[test]
 fn test_date_from_string() {
     assert_eq!(
-        Date::try_from(String::from("1234-56-78")).expect("date conversion failed"),
-        Date(String::from("1234-56-78")),
+        Date::try_from(String::from("1234-05-18")).expect("date conversion failed"),
+        Date(String::from("1234-05-18")),
     )
 }
 
 #[test]
 fn test_date_to_string() {
     assert_eq!(
-        String::from("1234-56-78"),
-        String::from(Date(String::from("1234-56-78")))
+        String::from("1234-05-18"),
+        String::from(Date(String::from("1234-05-18")))
     )
 }
 
 #[test]
 fn test_date_to_str() {
     assert_eq!(
-        "1234-56-78",
-        &String::from(Date(String::from("1234-56-78")))
+        "1234-05-18",
+        &String::from(Date(String::from("1234-05-18")))
     )
 }
 
 #[test]
 fn test_date_deserialize() {
     assert_eq!(
-        Date(String::from("1234-56-78")),
-        serde_json::from_str::<Date>("\"1234-56-78\"").expect("date could not be deserialized")
+        Date(String::from("1234-05-18")),
+        serde_json::from_str::<Date>("\"1234-05-18\"").expect("date could not be deserialized")
     )
 }
 
 #[test]
 fn test_date_display() {
     assert_eq!(
-        String::from("1234-56-78"),
-        format!("{}", Date(String::from("1234-56-78")))
+        String::from("1234-05-18"),
+        format!("{}", Date(String::from("1234-05-18")))
+    )
+}
+
+#[test]
+fn test_date_ord() {
+    let mut dates = vec![
+        Date(String::from("2024-03-01")),
+        Date(String::from("2023-12-31")),
+        Date(String::from("2024-02-29")),
+    ];
+    dates.sort();
+    assert_eq!(
+        dates,
+        vec![
+            Date(String::from("2023-12-31")),
+            Date(String::from("2024-02-29")),
+            Date(String::from("2024-03-01")),
+        ]
+    );
+    assert!(Date(String::from("2024-01-01")) > Date(String::from("2023-12-31")))
+}
+
+#[test]
+fn test_date_components() {
+    let date = Date(String::from("2024-02-29"));
+    assert_eq!(date.year(), 2024);
+    assert_eq!(date.month(), 2);
+    assert_eq!(date.day(), 29);
+    assert_eq!(date.weekday(), 4);
+    assert_eq!(Date(String::from("1970-01-01")).weekday(), 4);
+    assert_eq!(Date(String::from("2024-03-03")).weekday(), 7)
+}
+
+#[test]
+fn test_date_arithmetic() {
+    let date = Date(String::from("2024-02-28"));
+    assert_eq!(date.succ(), Some(Date(String::from("2024-02-29"))));
+    assert_eq!(date.pred(), Some(Date(String::from("2024-02-27"))));
+    assert_eq!(date.add_days(2), Some(Date(String::from("2024-03-01"))));
+    assert_eq!(date.add_days(-59), Some(Date(String::from("2023-12-31"))));
+    assert_eq!(date.add_days(366), Some(Date(String::from("2025-02-28"))));
+    assert_eq!(Date(String::from("9999-12-31")).succ(), None);
+    assert_eq!(Date(String::from("0000-01-01")).pred(), None);
+    assert_eq!(date.add_days(i64::MAX), None);
+    assert_eq!(
+        Date(String::from("2025-01-01")).days_since(&Date(String::from("2024-01-01"))),
+        366
+    );
+    assert_eq!(
+        Date(String::from("2024-01-01")).days_since(&Date(String::from("2024-01-31"))),
+        -30
+    )
+}
+
+#[test]
+fn test_date_boundaries() {
+    assert_eq!(
+        Date(String::from("2024-03-01")).start_of_week(),
+        Some(Date(String::from("2024-02-26")))
+    );
+    assert_eq!(
+        Date(String::from("2024-02-26")).start_of_week(),
+        Some(Date(String::from("2024-02-26")))
+    );
+    assert_eq!(
+        Date(String::from("2024-02-15")).start_of_month(),
+        Date(String::from("2024-02-01"))
+    );
+    assert_eq!(
+        Date(String::from("2024-02-15")).end_of_month(),
+        Date(String::from("2024-02-29"))
+    );
+    assert_eq!(
+        Date(String::from("2100-02-15")).end_of_month(),
+        Date(String::from("2100-02-28"))
+    )
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_date_chrono() {
+    let naive_date = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    assert_eq!(
+        chrono::NaiveDate::from(Date(String::from("2024-02-29"))),
+        naive_date
+    );
+    assert_eq!(
+        Date::try_from(naive_date).expect("date conversion failed"),
+        Date(String::from("2024-02-29"))
+    );
+    assert!(Date::try_from(chrono::NaiveDate::from_ymd_opt(10000, 1, 1).unwrap()).is_err())
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_date_time() {
+    let time_date = time::Date::from_calendar_date(2024, time::Month::February, 29).unwrap();
+    assert_eq!(
+        time::Date::from(Date(String::from("2024-02-29"))),
+        time_date
+    );
+    assert_eq!(
+        Date::try_from(time_date).expect("date conversion failed"),
+        Date(String::from("2024-02-29"))
     )
 }
 
 #[test]
 fn test_timestamp_new() {
     assert_eq!(
-        Timestamp(String::from("1234-56-78T12:34:56.789Z")),
-        Timestamp::new(String::from("1234-56-78T12:34:56.789Z"))
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        },
+        Timestamp::new(String::from("2022-03-15T14:59:18.825Z"))
+            .expect("timestamp conversion failed")
+    )
+}
+
+#[test]
+fn test_timestamp_new_precision() {
+    assert_eq!(
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 0
+        },
+        Timestamp::new(String::from("2022-03-15T14:59:18Z")).expect("timestamp conversion failed")
+    );
+    assert_eq!(
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 800000000
+        },
+        Timestamp::new(String::from("2022-03-15T14:59:18.8Z"))
+            .expect("timestamp conversion failed")
+    );
+    assert_eq!(
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825123456
+        },
+        Timestamp::new(String::from("2022-03-15T14:59:18.825123456Z"))
+            .expect("timestamp conversion failed")
+    );
+    assert_eq!(
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        },
+        Timestamp::new(String::from("2022-03-15T09:59:18.825-05:00"))
             .expect("timestamp conversion failed")
     )
 }
@@ -79,58 +244,225 @@ fn test_timestamp_new_error() {
         Timestamp::new(String::from("99-99-9999")).unwrap_err(),
         TypeError::TimestampError(String::from("invalid timestamp: 99-99-9999"))
     );
+    assert_eq!(
+        Timestamp::new(String::from("2022-02-30T14:59:18.825Z")).unwrap_err(),
+        TypeError::TimestampError(String::from("invalid timestamp: 2022-02-30T14:59:18.825Z"))
+    );
+    assert_eq!(
+        Timestamp::new(String::from("2022-03-15T24:59:18.825Z")).unwrap_err(),
+        TypeError::TimestampError(String::from("invalid timestamp: 2022-03-15T24:59:18.825Z"))
+    );
+    // non-ascii digits
+    assert_eq!(
+        Timestamp::new(String::from("2024-01-01T00:00:0١Z")).unwrap_err(),
+        TypeError::TimestampError(String::from("invalid timestamp: 2024-01-01T00:00:0١Z"))
+    );
+    assert!(Timestamp::try_from("2024-01-01T00:00:00.١Z").is_err());
+    assert!(Timestamp::try_from("2024-01-01T00:00:00+0١:00").is_err());
+    // offsets normalizing outside the four digit year range
+    assert_eq!(
+        Timestamp::new(String::from("0000-01-01T00:30:00+01:00")).unwrap_err(),
+        TypeError::TimestampError(String::from("invalid timestamp: 0000-01-01T00:30:00+01:00"))
+    );
+    assert_eq!(
+        Timestamp::new(String::from("9999-12-31T23:00:00-05:00")).unwrap_err(),
+        TypeError::TimestampError(String::from("invalid timestamp: 9999-12-31T23:00:00-05:00"))
+    );
+    assert!(Timestamp::new(String::from("0000-01-01T01:30:00+01:00")).is_ok());
+    assert!(Timestamp::new(String::from("9999-12-31T18:59:59-05:00")).is_ok());
+    assert_eq!(
+        Timestamp::new(String::from("2022-03-15T14:59:18.8251234567Z")).unwrap_err(),
+        TypeError::TimestampError(String::from(
+            "invalid timestamp: 2022-03-15T14:59:18.8251234567Z"
+        ))
+    );
 }
 
 #[test]
 fn test_timestamp_from_str() {
     assert_eq!(
-        Timestamp(String::from("1234-56-78T12:34:56.789Z")),
-        Timestamp::try_from("1234-56-78T12:34:56.789Z").expect("timestamp conversion failed")
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        },
+        Timestamp::try_from("2022-03-15T14:59:18.825Z").expect("timestamp conversion failed")
     )
 }
 
 #[test]
 fn test_timestamp_from_string() {
     assert_eq!(
-        Timestamp::try_from(String::from("1234-56-78T12:34:56.789Z"))
+        Timestamp::try_from(String::from("2022-03-15T14:59:18.825Z"))
             .expect("timestamp conversion failed"),
-        Timestamp(String::from("1234-56-78T12:34:56.789Z")),
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        },
     )
 }
 
 #[test]
 fn test_timestamp_to_string() {
     assert_eq!(
-        String::from("1234-56-78T12:34:56.789Z"),
-        String::from(Timestamp(String::from("1234-56-78T12:34:56.789Z")))
+        String::from("2022-03-15T14:59:18.825Z"),
+        String::from(Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        })
     )
 }
 
 #[test]
 fn test_timestamp_to_str() {
-    assert_eq!(
-        "1234-56-78T12:34:56.789Z",
-        &String::from(Timestamp(String::from("1234-56-78T12:34:56.789Z")))
-    )
+    let timestamp = Timestamp {
+        seconds: 1647356358,
+        nanoseconds: 825000000,
+    };
+    assert_eq!("2022-03-15T14:59:18.825Z", String::from(&timestamp))
 }
 
 #[test]
 fn test_timestamp_deserialize() {
     assert_eq!(
-        Timestamp(String::from("1234-56-78T12:34:56.789Z")),
-        serde_json::from_str::<Timestamp>("\"1234-56-78T12:34:56.789Z\"")
+        Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        },
+        serde_json::from_str::<Timestamp>("\"2022-03-15T14:59:18.825Z\"")
             .expect("timestamp could not be deserialized")
     )
 }
 
+#[test]
+fn test_timestamp_serialize() {
+    assert_eq!(
+        serde_json::to_string(&Timestamp {
+            seconds: 1647356358,
+            nanoseconds: 825000000
+        })
+        .expect("timestamp could not be serialized"),
+        "\"2022-03-15T14:59:18.825Z\""
+    )
+}
+
 #[test]
 fn test_timestamp_display() {
     assert_eq!(
-        String::from("1234-56-78T12:34:56.789Z"),
-        format!("{}", Timestamp(String::from("1234-56-78T12:34:56.789Z")))
+        String::from("2022-03-15T14:59:18.825Z"),
+        format!(
+            "{}",
+            Timestamp {
+                seconds: 1647356358,
+                nanoseconds: 825000000
+            }
+        )
+    );
+    assert_eq!(
+        String::from("2022-03-15T14:59:18.000Z"),
+        Timestamp::try_from("2022-03-15T14:59:18Z")
+            .expect("timestamp conversion failed")
+            .to_string()
+    );
+    assert_eq!(
+        String::from("2022-03-15T14:59:18.825100Z"),
+        Timestamp::try_from("2022-03-15T14:59:18.8251Z")
+            .expect("timestamp conversion failed")
+            .to_string()
+    );
+    assert_eq!(
+        String::from("1969-12-31T23:59:59.500Z"),
+        Timestamp {
+            seconds: -1,
+            nanoseconds: 500000000
+        }
+        .to_string()
+    )
+}
+
+#[test]
+fn test_timestamp_ord() {
+    let earlier = Timestamp::try_from("2022-03-15T14:59:18Z").expect("timestamp conversion failed");
+    let later = Timestamp::try_from("2022-03-15T14:59:18.1Z").expect("timestamp conversion failed");
+    let offset =
+        Timestamp::try_from("2022-03-15T15:59:18.000+02:00").expect("timestamp conversion failed");
+    assert!(earlier < later);
+    assert!(offset < earlier);
+    assert_eq!(
+        later.duration_since(&earlier),
+        Some(std::time::Duration::from_millis(100))
+    );
+    assert_eq!(earlier.duration_since(&later), None)
+}
+
+#[test]
+fn test_timestamp_date() {
+    assert_eq!(
+        Timestamp::try_from("2022-03-15T23:59:18.825-05:00")
+            .expect("timestamp conversion failed")
+            .date(),
+        Date(String::from("2022-03-16"))
     )
 }
 
+#[test]
+fn test_timestamp_system_time() {
+    let timestamp = Timestamp {
+        seconds: 1647356358,
+        nanoseconds: 825000000,
+    };
+    let system_time = std::time::UNIX_EPOCH + std::time::Duration::new(1647356358, 825000000);
+    assert_eq!(std::time::SystemTime::from(timestamp), system_time);
+    assert_eq!(
+        Timestamp::try_from(system_time).expect("timestamp conversion failed"),
+        timestamp
+    );
+    let before_epoch = Timestamp {
+        seconds: -2,
+        nanoseconds: 250000000,
+    };
+    let system_time = std::time::UNIX_EPOCH - std::time::Duration::from_millis(1750);
+    assert_eq!(std::time::SystemTime::from(before_epoch), system_time);
+    assert_eq!(
+        Timestamp::try_from(system_time).expect("timestamp conversion failed"),
+        before_epoch
+    );
+    assert!(Timestamp::now() > timestamp)
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_timestamp_chrono() {
+    let timestamp = Timestamp {
+        seconds: 1647356358,
+        nanoseconds: 825000000,
+    };
+    let datetime = chrono::DateTime::from_timestamp(1647356358, 825000000).unwrap();
+    assert_eq!(chrono::DateTime::<chrono::Utc>::from(timestamp), datetime);
+    assert_eq!(
+        Timestamp::try_from(datetime).expect("timestamp conversion failed"),
+        timestamp
+    );
+    assert_eq!(timestamp.to_local(), datetime)
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_timestamp_time() {
+    let timestamp = Timestamp {
+        seconds: 1647356358,
+        nanoseconds: 825000000,
+    };
+    let datetime = time::OffsetDateTime::from_unix_timestamp_nanos(1647356358825000000).unwrap();
+    assert_eq!(time::OffsetDateTime::from(timestamp), datetime);
+    assert_eq!(
+        Timestamp::try_from(datetime).expect("timestamp conversion failed"),
+        timestamp
+    );
+    let offset = time::UtcOffset::from_hms(-5, 0, 0).unwrap();
+    assert_eq!(timestamp.to_offset(offset).hour(), 9);
+    assert_eq!(timestamp.to_offset(offset), datetime)
+}
+
 #[test]
 fn test_time_new() {
     assert_eq!(
@@ -145,6 +477,32 @@ fn test_time_new_error() {
         Time::new(String::from("9:9")).unwrap_err(),
         TypeError::TimeError(String::from("invalid time: 9:9"))
     );
+    // non-ascii digits
+    assert_eq!(
+        Time::new(String::from("1٢:30")).unwrap_err(),
+        TypeError::TimeError(String::from("invalid time: 1٢:30"))
+    );
+    assert!(Time::try_from("12:3٤").is_err());
+}
+
+#[test]
+fn test_time_new_range_error() {
+    assert_eq!(
+        Time::new(String::from("99:99")).unwrap_err(),
+        TypeError::TimeError(String::from("invalid time: 99:99"))
+    );
+    assert_eq!(
+        Time::new(String::from("24:00")).unwrap_err(),
+        TypeError::TimeError(String::from("invalid time: 24:00"))
+    );
+    assert_eq!(
+        Time::new(String::from("12:60")).unwrap_err(),
+        TypeError::TimeError(String::from("invalid time: 12:60"))
+    );
+    assert_eq!(
+        Time(String::from("23:59")),
+        Time::new(String::from("23:59")).expect("time conversion failed")
+    )
 }
 
 #[test]
@@ -192,12 +550,66 @@ fn test_time_display() {
     )
 }
 
+#[test]
+fn test_time_ord() {
+    assert!(Time(String::from("09:59")) < Time(String::from("10:00")));
+    assert!(Time(String::from("23:00")) > Time(String::from("01:30")))
+}
+
+#[test]
+fn test_time_minutes() {
+    let time = Time(String::from("12:34"));
+    assert_eq!(time.hour(), 12);
+    assert_eq!(time.minute(), 34);
+    assert_eq!(time.minutes(), 754);
+    assert_eq!(Time::from_minutes(754), Ok(time));
+    assert_eq!(
+        Time::from_minutes(1440).unwrap_err(),
+        TypeError::TimeError(String::from("invalid time: 1440 minutes since midnight"))
+    )
+}
+
+#[test]
+fn test_time_minutes_until() {
+    assert_eq!(
+        Time(String::from("01:00")).minutes_until(&Time(String::from("03:00"))),
+        120
+    );
+    assert_eq!(
+        Time(String::from("22:30")).minutes_until(&Time(String::from("01:00"))),
+        150
+    );
+    assert_eq!(
+        Time(String::from("12:00")).minutes_until(&Time(String::from("12:00"))),
+        0
+    );
+    assert_eq!(
+        Time(String::from("22:30")).duration_until(&Time(String::from("01:00"))),
+        std::time::Duration::from_secs(9000)
+    )
+}
+
+#[test]
+fn test_time_duration() {
+    assert_eq!(
+        std::time::Duration::from(Time(String::from("12:34"))),
+        std::time::Duration::from_secs(45240)
+    );
+    assert_eq!(
+        Time::try_from(std::time::Duration::from_secs(45259)),
+        Ok(Time(String::from("12:34")))
+    );
+    assert!(Time::try_from(std::time::Duration::from_secs(86400)).is_err())
+}
+
 #[test]
 fn test_uuid_new() {
     assert_eq!(
-        UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")),
-        UUID::new(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
-            .expect("uuid conversion failed")
+        String::from(
+            UUID::new(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
+                .expect("uuid conversion failed")
+        ),
+        "4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"
     )
 }
 
@@ -212,9 +624,12 @@ fn test_uuid_new_error() {
 #[test]
 fn test_uuid_from_str() {
     assert_eq!(
-        UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")),
         UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed"),
-    )
+        UUID::new(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
+            .expect("uuid conversion failed"),
+    );
+    assert!(UUID::try_from("4c8d3f426efd4a7e85cad43164db0ab2").is_err());
+    assert!(UUID::try_from("{4c8d3f42-6efd-4a7e-85ca-d43164db0ab2}").is_err())
 }
 
 #[test]
@@ -222,7 +637,8 @@ fn test_uuid_from_string() {
     assert_eq!(
         UUID::try_from(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
             .expect("uuid conversion failed"),
-        UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")),
+        UUID::new(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
+            .expect("uuid conversion failed"),
     )
 }
 
@@ -230,22 +646,34 @@ fn test_uuid_from_string() {
 fn test_uuid_to_string() {
     assert_eq!(
         String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"),
-        String::from(UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")))
+        String::from(
+            UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
+        )
     )
 }
 
 #[test]
 fn test_uuid_to_str() {
+    let uuid =
+        UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed");
+    assert_eq!("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2", String::from(&uuid))
+}
+
+#[test]
+fn test_uuid_case() {
+    let uuid =
+        UUID::try_from("4C8D3F42-6EFD-4A7E-85CA-D43164DB0AB2").expect("uuid conversion failed");
     assert_eq!(
-        "4c8d3f42-6efd-4a7e-85ca-d43164db0ab2",
-        &String::from(UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")))
-    )
+        uuid,
+        UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
+    );
+    assert_eq!(uuid.to_string(), "4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
 }
 
 #[test]
 fn test_uuid_deserialize() {
     assert_eq!(
-        UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")),
+        UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed"),
         serde_json::from_str::<UUID>("\"4c8d3f42-6efd-4a7e-85ca-d43164db0ab2\"")
             .expect("uuid could not be deserialized")
     )
@@ -257,11 +685,83 @@ fn test_uuid_display() {
         String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"),
         format!(
             "{}",
-            UUID(String::from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"))
+            UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
         )
     )
 }
 
+#[test]
+fn test_uuid_serialize() {
+    assert_eq!(
+        serde_json::to_string(
+            &UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
+                .expect("uuid conversion failed")
+        )
+        .expect("uuid could not be serialized"),
+        "\"4c8d3f42-6efd-4a7e-85ca-d43164db0ab2\""
+    )
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn test_uuid_uuid() {
+    let uuid = uuid::Uuid::parse_str("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
+        .expect("uuid crate conversion failed");
+    assert_eq!(
+        UUID::from(uuid),
+        UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
+    );
+    assert_eq!(
+        uuid::Uuid::from(
+            UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
+        ),
+        uuid
+    )
+}
+
+// phantom resource for typed id tests
+#[derive(Debug)]
+struct Resource;
+
+#[test]
+fn test_id_uuid() {
+    let id = Id::<Resource>::from(
+        UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed"),
+    );
+    assert_eq!(
+        id.uuid(),
+        &UUID::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("uuid conversion failed")
+    );
+    assert_eq!(
+        Id::<Resource>::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
+            .expect("id conversion failed"),
+        id
+    );
+    assert_eq!(
+        Id::<Resource>::try_from("foo-bar-baz").unwrap_err(),
+        TypeError::UUIDError(String::from("invalid uuid: foo-bar-baz"))
+    );
+    assert_eq!(
+        String::from(UUID::from(id)),
+        "4c8d3f42-6efd-4a7e-85ca-d43164db0ab2"
+    )
+}
+
+#[test]
+fn test_id_serde() {
+    let id = serde_json::from_str::<Id<Resource>>("\"4c8d3f42-6efd-4a7e-85ca-d43164db0ab2\"")
+        .expect("id could not be deserialized");
+    assert_eq!(
+        id,
+        Id::try_from("4c8d3f42-6efd-4a7e-85ca-d43164db0ab2").expect("id conversion failed")
+    );
+    assert_eq!(
+        serde_json::to_string(&id).expect("id could not be serialized"),
+        "\"4c8d3f42-6efd-4a7e-85ca-d43164db0ab2\""
+    );
+    assert_eq!(id.to_string(), "4c8d3f42-6efd-4a7e-85ca-d43164db0ab2")
+}
+
 #[test]
 fn test_slug_new() {
     assert_eq!(
@@ -2,7 +2,7 @@
 //!
 //! `model::utilization` is a model for the Ruddr Utilization target period object. This module is not publically accessible, but the structs and members are public for reading from `interface::utilization` returns.
 //! [API Documentation](https://docs.ruddr.io/api-reference/utilization-target-periods/get-a-utilization-target-period.md)
-use crate::model::types;
+use crate::model::{shared, types};
 use serde::{Deserialize, Serialize};
 
 /// Model for Utilizations used with List operations.
@@ -17,7 +17,7 @@ pub struct Utilizations {
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Utilization {
-    pub id: types::UUID,
+    pub id: types::Id<Utilization>,
     pub start: types::Date,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_percentage: Option<f64>,
@@ -25,6 +25,9 @@ pub struct Utilization {
     pub is_default: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end: Option<types::Date>,
+    /// Fields returned by the Ruddr API which are not yet modeled by this crate.
+    #[serde(flatten, deserialize_with = "shared::extra::<Utilization, _>")]
+    pub extra: shared::Extra,
 }
 
 #[cfg(test)]
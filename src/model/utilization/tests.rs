@@ -14,12 +14,15 @@ fn test_utilization_deserialize() {
     let utilization_deserialized = serde_json::from_str::<Utilization>(json_input)
         .expect("utilization could not be deserialized");
     let utilization = Utilization {
-        id: types::UUID(String::from("8e6d6316-5bc2-4135-b99c-f604f29051ab")),
+        id: types::Id::try_from("8e6d6316-5bc2-4135-b99c-f604f29051ab")
+            .expect("uuid conversion failed"),
         start: types::Date(String::from("2024-11-01")),
         target_percentage: Some(85.0),
-        created_at: types::Timestamp(String::from("2024-11-08T00:50:42.006Z")),
+        created_at: types::Timestamp::try_from("2024-11-08T00:50:42.006Z")
+            .expect("timestamp conversion failed"),
         is_default: false,
         end: Some(types::Date(String::from("2025-10-31"))),
+        extra: shared::Extra::new(),
     };
     assert_eq!(
         utilization_deserialized, utilization,
@@ -42,3 +45,23 @@ fn test_utilization_deserialize() {
         "utilizations did not contain the expected values"
     );
 }
+
+#[test]
+fn test_utilization_extra() {
+    let json_input = r#"{"id":"8e6d6316-5bc2-4135-b99c-f604f29051ab","start":"2024-11-01","targetPercentage":85.0,"createdAt":"2024-11-08T00:50:42.006Z","isDefault":false,"end":"2025-10-31","practice":{"id":"400a0c5c-32f1-41f5-a51d-e90807254965","name":"Retail"},"isLocked":true}"#;
+    let utilization = serde_json::from_str::<Utilization>(json_input)
+        .expect("utilization could not be deserialized");
+    assert_eq!(
+        utilization.extra.keys().collect::<Vec<&String>>(),
+        vec!["isLocked", "practice"]
+    );
+    assert_eq!(utilization.extra["isLocked"], serde_json::Value::Bool(true));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(
+            &serde_json::to_string(&utilization).expect("utilization could not be serialized")
+        )
+        .expect("serialized utilization is not valid json"),
+        serde_json::from_str::<serde_json::Value>(json_input).expect("input is not valid json"),
+        "utilization did not round trip the unrecognized fields"
+    )
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "blocking")]
+use ruddr::blocking::client;
+use ruddr::blocking::interface::*;
+use ruddr::model;
+use ruddr::model::types;
+
+#[test]
+fn test_allocations() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        allocation::allocations(
+            &client,
+            &allocation::AllocationsQuery {
+                assignment_type: Some(model::allocation::AssignmentType::Project),
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..allocation::AllocationsQuery::overlapping(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                )
+            },
+        )
+        .expect_err("allocations retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_customers() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        customer::clients(
+            &client,
+            &customer::ClientsQuery {
+                code: Some("JOE"),
+                ..Default::default()
+            },
+        )
+        .expect_err("clients retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_costs() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        cost::costs(
+            &client,
+            &cost::CostsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
+        )
+        .expect_err("costs retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_expense_items() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        expense_item::expense_items(
+            &client,
+            &expense_item::ExpenseItemsQuery {
+                expense_report: Some(
+                    types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
+        )
+        .expect_err("expense_items retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_expense_reports() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        expense_report::expense_reports(&client, &expense_report::ExpenseReportsQuery::default())
+            .expect_err("expense_reports retrieval did not fail on auth")
+            .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_members() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        member::members(
+            &client,
+            &member::MembersQuery {
+                name_contains: Some("Joe"),
+                email_contains: Some("foo@bar.com"),
+                ..Default::default()
+            },
+        )
+        .expect_err("members retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_projects() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        project::projects(
+            &client,
+            &project::ProjectsQuery {
+                client_id: Some(
+                    types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                        .expect("uuid conversion failed")
+                ),
+                project_type: Some(
+                    types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de")
+                        .expect("uuid conversion failed")
+                ),
+                status: Some(model::project::Status::InProgress),
+                name_contains: Some("my_project"),
+                ..Default::default()
+            },
+        )
+        .expect_err("projects retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_roles() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        role::roles(
+            &client,
+            &role::RolesQuery {
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
+        )
+        .expect_err("roles retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_time_entries() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        time::time_entries(
+            &client,
+            &time::TimeEntriesQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("uuid conversion failed")
+                ),
+                date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+                begin_date: Some(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed")
+                ),
+                ..Default::default()
+            },
+        )
+        .expect_err("time entries retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
+
+#[test]
+fn test_utilizations() {
+    let client = client::Client::new(Some("abcdefghi123456789"))
+        .expect("client with token could not be constructed");
+    assert_eq!(
+        utilization::utilizations(
+            &client,
+            &utilization::UtilizationsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
+        )
+        .expect_err("utilizations retrieval did not fail on auth")
+        .status(),
+        Some(reqwest::StatusCode::UNAUTHORIZED),
+    )
+}
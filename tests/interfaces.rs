@@ -10,15 +10,17 @@ async fn test_allocations() {
     assert_eq!(
         allocation::allocations(
             &client,
-            Some(model::allocation::AssignmentType::Project),
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("uuid conversion failed")
-            ),
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            None,
+            &allocation::AllocationsQuery {
+                assignment_type: Some(model::allocation::AssignmentType::Project),
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..allocation::AllocationsQuery::overlapping(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                    types::Date::try_from("2024-01-01").expect("date conversion failed"),
+                )
+            },
         )
         .await
         .expect_err("allocations retrieval did not fail on auth")
@@ -32,10 +34,16 @@ async fn test_customers() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        customer::clients(&client, Some("JOE"), None, None)
-            .await
-            .expect_err("clients retrieval did not fail on auth")
-            .status(),
+        customer::clients(
+            &client,
+            &customer::ClientsQuery {
+                code: Some("JOE"),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("clients retrieval did not fail on auth")
+        .status(),
         Some(reqwest::StatusCode::UNAUTHORIZED),
     )
 }
@@ -47,12 +55,13 @@ async fn test_costs() {
     assert_eq!(
         cost::costs(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
+            &cost::CostsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("costs retrieval did not fail on auth")
@@ -68,12 +77,13 @@ async fn test_expense_items() {
     assert_eq!(
         expense_item::expense_items(
             &client,
-            Some(
-                types::UUID::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
+            &expense_item::ExpenseItemsQuery {
+                expense_report: Some(
+                    types::Id::try_from("2bdab00d-86fb-46dc-ae05-7cc9c4aedc80")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("expense_items retrieval did not fail on auth")
@@ -87,7 +97,7 @@ async fn test_expense_reports() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        expense_report::expense_reports(&client, None, None)
+        expense_report::expense_reports(&client, &expense_report::ExpenseReportsQuery::default())
             .await
             .expect_err("expense_reports retrieval did not fail on auth")
             .status(),
@@ -100,10 +110,17 @@ async fn test_members() {
     let client = client::Client::new(Some("abcdefghi123456789"))
         .expect("client with token could not be constructed");
     assert_eq!(
-        member::members(&client, Some("Joe"), Some("foo@bar.com"), None, None)
-            .await
-            .expect_err("members retrieval did not fail on auth")
-            .status(),
+        member::members(
+            &client,
+            &member::MembersQuery {
+                name_contains: Some("Joe"),
+                email_contains: Some("foo@bar.com"),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("members retrieval did not fail on auth")
+        .status(),
         Some(reqwest::StatusCode::UNAUTHORIZED),
     )
 }
@@ -115,18 +132,19 @@ async fn test_projects() {
     assert_eq!(
         project::projects(
             &client,
-            Some(
-                types::UUID::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
-                    .expect("uuid conversion failed")
-            ),
-            Some(
-                types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de")
-                    .expect("uuid conversion failed")
-            ),
-            Some(model::project::Status::InProgress),
-            Some("my_project"),
-            None,
-            None,
+            &project::ProjectsQuery {
+                client_id: Some(
+                    types::Id::try_from("d5afaffe-09e5-4d73-b02c-905b40fc6c22")
+                        .expect("uuid conversion failed")
+                ),
+                project_type: Some(
+                    types::UUID::try_from("9b0927a6-35a1-4795-a4ca-10167b05f7de")
+                        .expect("uuid conversion failed")
+                ),
+                status: Some(model::project::Status::InProgress),
+                name_contains: Some("my_project"),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("projects retrieval did not fail on auth")
@@ -142,12 +160,13 @@ async fn test_roles() {
     assert_eq!(
         role::roles(
             &client,
-            Some(
-                types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
+            &role::RolesQuery {
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("roles retrieval did not fail on auth")
@@ -163,23 +182,21 @@ async fn test_time_entries() {
     assert_eq!(
         time::time_entries(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("uuid conversion failed")
-            ),
-            Some(
-                types::UUID::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
-            None,
-            None,
-            None,
-            None,
+            &time::TimeEntriesQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                project: Some(
+                    types::Id::try_from("095e0780-48bf-472c-8deb-2fc3ebc7d90c")
+                        .expect("uuid conversion failed")
+                ),
+                date: Some(types::Date::try_from("2024-01-01").expect("date conversion failed")),
+                begin_date: Some(
+                    types::Date::try_from("2024-01-01").expect("date conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("time entries retrieval did not fail on auth")
@@ -195,12 +212,13 @@ async fn test_utilizations() {
     assert_eq!(
         utilization::utilizations(
             &client,
-            Some(
-                types::UUID::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
-                    .expect("uuid conversion failed")
-            ),
-            None,
-            None,
+            &utilization::UtilizationsQuery {
+                member: Some(
+                    types::Id::try_from("ec5543de-3b0f-47a0-b8ef-a6e18dc4b885")
+                        .expect("uuid conversion failed")
+                ),
+                ..Default::default()
+            },
         )
         .await
         .expect_err("utilizations retrieval did not fail on auth")